@@ -0,0 +1,87 @@
+//! Compares reading a frame the way `deserialize_all` did before the cursor,
+//! [`deserialize_single_row`] on a clone of the frame for every row, and row by
+//! row with [`deserialize_single_row_borrowed`], against [`iter_rows`].
+//!
+//! `cargo bench --bench row_cursor`, set `ROWS` to change the frame height.
+use std::time::Instant;
+
+use polars::{frame::DataFrame, prelude::NamedFrom, series::Series};
+use polars_deser_row::{deserialize_single_row, deserialize_single_row_borrowed, iter_rows};
+
+#[allow(dead_code)]
+#[derive(serde::Deserialize)]
+struct Row<'a> {
+    id: i64,
+    score: f64,
+    flag: bool,
+    count: Option<u32>,
+    name: &'a str,
+}
+
+/// [`Row`] owning its name, for [`deserialize_single_row`].
+#[allow(dead_code)]
+#[derive(serde::Deserialize)]
+struct OwnedRow {
+    id: i64,
+    score: f64,
+    flag: bool,
+    count: Option<u32>,
+    name: String,
+}
+
+fn main() {
+    let rows: usize = std::env::var("ROWS")
+        .ok()
+        .and_then(|i| i.parse().ok())
+        .unwrap_or(10_000_000);
+
+    let df = DataFrame::new(vec![
+        Series::new("id", (0..rows as i64).collect::<Vec<_>>()),
+        Series::new("score", (0..rows).map(|i| i as f64 / 3.).collect::<Vec<_>>()),
+        Series::new("flag", (0..rows).map(|i| i % 2 == 0).collect::<Vec<_>>()),
+        Series::new(
+            "count",
+            (0..rows)
+                .map(|i| (i % 7 != 0).then_some(i as u32))
+                .collect::<Vec<_>>(),
+        ),
+        Series::new(
+            "name",
+            (0..rows).map(|i| format!("user-{i}")).collect::<Vec<_>>(),
+        ),
+        Series::new("unused", vec![0u8; rows]),
+    ])
+    .unwrap();
+
+    let start = Instant::now();
+    let mut sum = 0;
+    for row_idx in 0..df.height() {
+        let row: OwnedRow = deserialize_single_row(df.clone(), row_idx).unwrap();
+        sum += row.id;
+    }
+    let clone_per_row = start.elapsed();
+    println!("clone per row:  {clone_per_row:?} (checksum {sum})");
+
+    let start = Instant::now();
+    let mut sum = 0;
+    for row_idx in 0..df.height() {
+        let row: Row = deserialize_single_row_borrowed(&df, row_idx).unwrap();
+        sum += row.id;
+    }
+    let per_row = start.elapsed();
+    println!("per row lookup: {per_row:?} (checksum {sum})");
+
+    let start = Instant::now();
+    let mut sum = 0;
+    for row in iter_rows::<Row>(&df) {
+        sum += row.unwrap().id;
+    }
+    let cursor = start.elapsed();
+    println!("iter_rows:      {cursor:?} (checksum {sum})");
+
+    println!(
+        "speedup:        {:.1}x over clone per row, {:.1}x over per row lookup",
+        clone_per_row.as_secs_f64() / cursor.as_secs_f64(),
+        per_row.as_secs_f64() / cursor.as_secs_f64()
+    );
+}
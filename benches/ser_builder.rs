@@ -0,0 +1,121 @@
+//! Times [`serialize_into_dataframe`] against the serializer it replaced,
+//! which built a one-element `Series` for every value and extended its column
+//! with it, and against building the same frame from one `Vec` per column, the
+//! floor for any row serializer.
+//!
+//! `cargo bench --bench ser_builder`, set `ROWS` to change the frame height.
+use std::{collections::HashMap, time::Instant};
+
+use polars::{frame::DataFrame, prelude::NamedFrom, series::Series};
+use polars_deser_row::serialize_into_dataframe;
+
+#[derive(serde::Serialize)]
+struct Row {
+    id: i64,
+    score: f64,
+    flag: bool,
+    count: Option<u32>,
+    name: String,
+    tags: Vec<u16>,
+}
+
+fn row(i: usize) -> Row {
+    Row {
+        id: i as i64,
+        score: i as f64 / 3.,
+        flag: i % 2 == 0,
+        count: (i % 7 != 0).then_some(i as u32),
+        name: format!("user-{i}"),
+        tags: (0..i % 4).map(|i| i as u16).collect(),
+    }
+}
+
+const NAMES: [&str; 6] = ["id", "score", "flag", "count", "name", "tags"];
+
+/// The serializer before the column builders: every value a `Series` of its
+/// own, the column taken out of the map and put back for each of them.
+fn series_per_value(rows: usize) -> DataFrame {
+    let mut columns: HashMap<&str, Series> = HashMap::new();
+    let mut push = |name: &'static str, value: Series| match columns.remove(name) {
+        Some(mut column) => {
+            column.extend(&value).unwrap();
+            columns.insert(name, column);
+        }
+        None => {
+            columns.insert(name, value);
+        }
+    };
+    for row in (0..rows).map(row) {
+        push("id", Series::new("id", [row.id]));
+        push("score", Series::new("score", [row.score]));
+        push("flag", Series::new("flag", [row.flag]));
+        push("count", Series::new("count", [row.count]));
+        push("name", Series::new("name", [row.name]));
+        push("tags", Series::new("tags", [Series::new("", row.tags)]));
+    }
+    DataFrame::new(
+        NAMES
+            .iter()
+            .filter_map(|name| columns.remove(name))
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn main() {
+    let rows: usize = std::env::var("ROWS")
+        .ok()
+        .and_then(|i| i.parse().ok())
+        .unwrap_or(10_000_000);
+
+    let start = Instant::now();
+    let df = serialize_into_dataframe((0..rows).map(row)).unwrap();
+    let serde = start.elapsed();
+    println!("serialize_into_dataframe: {serde:?} ({} rows)", df.height());
+
+    let start = Instant::now();
+    let df = series_per_value(rows);
+    let old = start.elapsed();
+    println!("series per value:         {old:?} ({} rows)", df.height());
+
+    let start = Instant::now();
+    let (mut id, mut score, mut flag, mut count, mut name, mut tags) = (
+        Vec::with_capacity(rows),
+        Vec::with_capacity(rows),
+        Vec::with_capacity(rows),
+        Vec::with_capacity(rows),
+        Vec::with_capacity(rows),
+        Vec::with_capacity(rows),
+    );
+    for row in (0..rows).map(row) {
+        id.push(row.id);
+        score.push(row.score);
+        flag.push(row.flag);
+        count.push(row.count);
+        name.push(row.name);
+        tags.push(Series::new("", row.tags));
+    }
+    let df = DataFrame::new(vec![
+        Series::new("id", id),
+        Series::new("score", score),
+        Series::new("flag", flag),
+        Series::new("count", count),
+        Series::new("name", name),
+        Series::new("tags", tags),
+    ])
+    .unwrap();
+    let columnar = start.elapsed();
+    println!(
+        "column vecs:              {columnar:?} ({} rows)",
+        df.height()
+    );
+
+    println!(
+        "speedup:                  {:.1}x",
+        old.as_secs_f64() / serde.as_secs_f64()
+    );
+    println!(
+        "overhead:                 {:.1}x",
+        serde.as_secs_f64() / columnar.as_secs_f64()
+    );
+}
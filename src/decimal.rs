@@ -0,0 +1,44 @@
+//! Write `rust_decimal::Decimal`s to Decimal columns.
+//!
+//! `#[serde(with = "polars_deser_row::decimal")]` puts a `Decimal` or
+//! `Option<Decimal>` field in a `Decimal(38, scale)` column. The scale is set
+//! with [`crate::SerializeOptions::with_decimal_scale`], or else grows to fit
+//! the values of the column. A field without the attribute keeps the text
+//! rust_decimal writes for it and gets a String column.
+//!
+//! Reading doesn't need the attribute: cells of Decimal columns are handed to
+//! rust_decimal as strings, and to `i128` fields as their unscaled value.
+//! Human readable serializers other than this crate's see the plain
+//! rust_decimal value, the others its unscaled value and scale.
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Payment {
+//!     #[serde(with = "polars_deser_row::decimal")]
+//!     amount: rust_decimal::Decimal,
+//!     #[serde(with = "polars_deser_row::decimal")]
+//!     fee: Option<rust_decimal::Decimal>,
+//! }
+//! ```
+use rust_decimal::Decimal;
+
+use crate::{ser_root::DECIMAL, wrapped::Physical};
+
+/// Values written to a Decimal column, see the [module docs](self).
+pub use crate::wrapped::Wrapped as DecimalValue;
+pub use crate::wrapped::{deserialize, serialize};
+
+/// The unscaled value and scale.
+impl Physical for Decimal {
+    const NAME: &'static str = DECIMAL;
+
+    type Repr = (i128, u32);
+
+    fn to_physical(&self) -> (i128, u32) {
+        (self.mantissa(), self.scale())
+    }
+
+    fn from_physical((unscaled, scale): (i128, u32)) -> Option<Self> {
+        Decimal::try_from_i128_with_scale(unscaled, scale).ok()
+    }
+}
@@ -0,0 +1,497 @@
+use std::{borrow::Cow, marker::PhantomData, rc::Rc};
+
+use polars::{
+    datatypes::DataType,
+    export::arrow::array::{Array, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8ViewArray},
+    frame::DataFrame,
+    series::Series,
+};
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+use crate::{
+    deser_map::Projections, deser_root::SeriesDeserItem, deser_series::SeriesDeser,
+    pl_row_error::PlRowSerdeError,
+};
+
+/// Arrays of a column, downcast once per frame.
+enum TypedChunks<'de> {
+    Boolean(Vec<&'de BooleanArray>),
+    Int8(Vec<&'de PrimitiveArray<i8>>),
+    Int16(Vec<&'de PrimitiveArray<i16>>),
+    Int32(Vec<&'de PrimitiveArray<i32>>),
+    Int64(Vec<&'de PrimitiveArray<i64>>),
+    UInt8(Vec<&'de PrimitiveArray<u8>>),
+    UInt16(Vec<&'de PrimitiveArray<u16>>),
+    UInt32(Vec<&'de PrimitiveArray<u32>>),
+    UInt64(Vec<&'de PrimitiveArray<u64>>),
+    Float32(Vec<&'de PrimitiveArray<f32>>),
+    Float64(Vec<&'de PrimitiveArray<f64>>),
+    String(Vec<&'de Utf8ViewArray>),
+    Binary(Vec<&'de BinaryViewArray>),
+    /// Everything else is read through [`SeriesDeserItem`].
+    Other,
+}
+
+impl<'de> TypedChunks<'de> {
+    fn new(series: &'de Series) -> Self {
+        macro_rules! downcast {
+            ($variant: ident, $cast: ident) => {
+                match series.$cast() {
+                    Ok(ca) => Self::$variant(ca.downcast_iter().collect()),
+                    Err(_) => Self::Other,
+                }
+            };
+        }
+        match series.dtype() {
+            DataType::Boolean => downcast!(Boolean, bool),
+            DataType::Int8 => downcast!(Int8, i8),
+            DataType::Int16 => downcast!(Int16, i16),
+            DataType::Int32 => downcast!(Int32, i32),
+            DataType::Int64 => downcast!(Int64, i64),
+            DataType::UInt8 => downcast!(UInt8, u8),
+            DataType::UInt16 => downcast!(UInt16, u16),
+            DataType::UInt32 => downcast!(UInt32, u32),
+            DataType::UInt64 => downcast!(UInt64, u64),
+            DataType::Float32 => downcast!(Float32, f32),
+            DataType::Float64 => downcast!(Float64, f64),
+            DataType::String => downcast!(String, str),
+            DataType::Binary => downcast!(Binary, binary),
+            _ => Self::Other,
+        }
+    }
+
+    /// `None` when the column is not one of the typed ones.
+    fn is_valid(&self, chunk_idx: usize, local_idx: usize) -> Option<bool> {
+        macro_rules! is_valid {
+            ($($variant: ident),*) => {
+                match self {
+                    $(Self::$variant(arrays) => Some(arrays[chunk_idx].is_valid(local_idx)),)*
+                    Self::Other => None,
+                }
+            };
+        }
+        is_valid!(
+            Boolean, Int8, Int16, Int32, Int64, UInt8, UInt16, UInt32, UInt64, Float32, Float64,
+            String, Binary
+        )
+    }
+}
+
+/// A column resolved for one field, with a cursor over its chunks.
+struct ColumnCursor<'de> {
+    field: &'static str,
+    series: &'de Series,
+    chunks: TypedChunks<'de>,
+    chunk_lens: Vec<usize>,
+    chunk_idx: usize,
+    /// Row index of the first row of `chunk_idx`.
+    chunk_offset: usize,
+}
+
+impl<'de> ColumnCursor<'de> {
+    fn new(field: &'static str, series: &'de Series) -> Self {
+        Self {
+            field,
+            series,
+            chunks: TypedChunks::new(series),
+            chunk_lens: series.chunks().iter().map(|i| i.len()).collect(),
+            chunk_idx: 0,
+            chunk_offset: 0,
+        }
+    }
+
+    /// Move the cursor to `row_idx` and return its index within the current
+    /// chunk. Moving forward is amortized O(1), which is how rows are read.
+    fn seek(&mut self, row_idx: usize) -> Option<usize> {
+        if row_idx < self.chunk_offset {
+            self.chunk_idx = 0;
+            self.chunk_offset = 0;
+        }
+        while let Some(len) = self.chunk_lens.get(self.chunk_idx) {
+            if row_idx < self.chunk_offset + len {
+                return Some(row_idx - self.chunk_offset);
+            }
+            self.chunk_offset += len;
+            self.chunk_idx += 1;
+        }
+        None
+    }
+}
+
+/// State shared by every row read through a [`RowCursor`].
+struct CursorState<'de> {
+    df: &'de DataFrame,
+    fields: Option<&'static [&'static str]>,
+    columns: Vec<ColumnCursor<'de>>,
+    /// The projections of the struct cells read through the fallback.
+    projections: Rc<Projections>,
+}
+
+impl<'de> CursorState<'de> {
+    /// Resolve `fields` against the frame, unless that was already done.
+    fn resolve(&mut self, fields: &'static [&'static str]) {
+        if let Some(resolved) = self.fields {
+            if std::ptr::eq(resolved, fields) || resolved == fields {
+                return;
+            }
+        }
+        let df = self.df;
+        self.columns = fields
+            .iter()
+            .filter_map(|field| df.column(field).ok().map(|s| ColumnCursor::new(*field, s)))
+            .collect();
+        self.fields = Some(fields);
+    }
+}
+
+/// Reads rows of a dataframe one after another.
+///
+/// The columns named by the fields of `T` are looked up and downcast to
+/// their arrow arrays once, on the first row. Every row after that reads
+/// primitive, string and binary cells straight from the arrays. Other
+/// dtypes are read the same way [`crate::deserialize_single_row`] reads them.
+pub struct RowCursor<'de, T> {
+    state: CursorState<'de>,
+    _p: PhantomData<fn() -> T>,
+}
+
+impl<'de, T> RowCursor<'de, T>
+where
+    T: Deserialize<'de>,
+{
+    pub fn new(df: &'de DataFrame) -> Self {
+        Self {
+            state: CursorState {
+                df,
+                fields: None,
+                columns: vec![],
+                projections: Rc::default(),
+            },
+            _p: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.df.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Deserialize the row at `row_idx`.
+    pub fn row(&mut self, row_idx: usize) -> Result<T, PlRowSerdeError> {
+        T::deserialize(CursorRow {
+            state: &mut self.state,
+            row_idx,
+        })
+    }
+}
+
+/// Lazily deserializes every row of a dataframe, see [`RowCursor`].
+pub struct DataFrameRowIter<'de, T> {
+    cursor: RowCursor<'de, T>,
+    row_idx: usize,
+}
+
+impl<'de, T> DataFrameRowIter<'de, T>
+where
+    T: Deserialize<'de>,
+{
+    pub fn new(df: &'de DataFrame) -> Self {
+        Self {
+            cursor: RowCursor::new(df),
+            row_idx: 0,
+        }
+    }
+}
+
+impl<'de, T> Iterator for DataFrameRowIter<'de, T>
+where
+    T: Deserialize<'de>,
+{
+    type Item = Result<T, PlRowSerdeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row_idx >= self.cursor.len() {
+            return None;
+        }
+        let res = self.cursor.row(self.row_idx);
+        self.row_idx += 1;
+        Some(res)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cursor.len().saturating_sub(self.row_idx);
+        (len, Some(len))
+    }
+}
+
+impl<'de, T> ExactSizeIterator for DataFrameRowIter<'de, T> where T: Deserialize<'de> {}
+
+struct CursorRow<'a, 'de> {
+    state: &'a mut CursorState<'de>,
+    row_idx: usize,
+}
+
+impl<'a, 'de> CursorRow<'a, 'de> {
+    fn fallback(self) -> SeriesDeser<'de> {
+        SeriesDeser::new(self.state.df, self.row_idx)
+            .with_projections(Rc::clone(&self.state.projections))
+    }
+}
+
+/// Forward to the deserializer returned by `self.fallback()`.
+macro_rules! forward_to_fallback {
+    ($($func: ident $(($($arg: ident: $ty: ty),*))?;)*) => {
+        $(
+            fn $func<V>(self, $($($arg: $ty,)*)? visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                self.fallback().$func($($($arg,)*)? visitor)
+            }
+        )*
+    };
+}
+
+impl<'a, 'de> Deserializer<'de> for CursorRow<'a, 'de> {
+    type Error = PlRowSerdeError;
+
+    fn deserialize_struct<V>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let CursorRow { state, row_idx } = self;
+        if row_idx >= state.df.height() {
+            return Err(PlRowSerdeError::row_out_of_range(
+                row_idx,
+                state.df.height(),
+            ));
+        }
+        state.resolve(fields);
+        visitor
+            .visit_map(CursorMapAccess {
+                columns: state.columns.iter_mut(),
+                current: None,
+                projections: &state.projections,
+                row_idx,
+            })
+            .map_err(|e| e.at_row(row_idx))
+    }
+
+    forward_to_fallback! {
+        deserialize_any;
+        deserialize_bool;
+        deserialize_i8;
+        deserialize_i16;
+        deserialize_i32;
+        deserialize_i64;
+        deserialize_u8;
+        deserialize_u16;
+        deserialize_u32;
+        deserialize_u64;
+        deserialize_i128;
+        deserialize_u128;
+        deserialize_f32;
+        deserialize_f64;
+        deserialize_char;
+        deserialize_str;
+        deserialize_string;
+        deserialize_bytes;
+        deserialize_byte_buf;
+        deserialize_option;
+        deserialize_unit;
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq;
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map;
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier;
+        deserialize_ignored_any;
+    }
+}
+
+struct CursorMapAccess<'a, 'de> {
+    columns: std::slice::IterMut<'a, ColumnCursor<'de>>,
+    current: Option<&'a mut ColumnCursor<'de>>,
+    projections: &'a Rc<Projections>,
+    row_idx: usize,
+}
+
+impl<'a, 'de> MapAccess<'de> for CursorMapAccess<'a, 'de> {
+    type Error = PlRowSerdeError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        self.current = self.columns.next();
+        match &self.current {
+            None => Ok(None),
+            Some(column) => seed
+                .deserialize(serde::de::value::BorrowedStrDeserializer::new(column.field))
+                .map(Some),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let Some(column) = self.current.take() else {
+            return Err(PlRowSerdeError::custom("value requested without a key"));
+        };
+        let local_idx = column.seek(self.row_idx);
+        let field = column.field;
+        seed.deserialize(CursorCell {
+            column,
+            local_idx,
+            projections: self.projections,
+            row_idx: self.row_idx,
+        })
+        .map_err(|e| e.within(field, self.row_idx))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.columns.len())
+    }
+}
+
+/// Deserializer for a single cell read through a [`ColumnCursor`].
+struct CursorCell<'a, 'de> {
+    column: &'a ColumnCursor<'de>,
+    /// `None` when the row is out of range.
+    local_idx: Option<usize>,
+    projections: &'a Rc<Projections>,
+    row_idx: usize,
+}
+
+impl<'a, 'de> CursorCell<'a, 'de> {
+    fn fallback(self) -> SeriesDeserItem<'de> {
+        SeriesDeserItem {
+            series: Cow::Borrowed(self.column.series),
+            row_idx: self.row_idx,
+            projections: Some(Rc::clone(self.projections)),
+        }
+    }
+
+    fn is_valid(&self) -> Option<bool> {
+        self.local_idx.and_then(|local_idx| {
+            self.column
+                .chunks
+                .is_valid(self.column.chunk_idx, local_idx)
+        })
+    }
+}
+
+/// Read the cell straight from the array when the column has the dtype asked
+/// for and the cell is valid. Anything else goes through the fallback, which
+/// also produces the errors.
+macro_rules! read_typed {
+    ($func: ident, $variant: ident, $visit: ident) => {
+        fn $func<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            match (&self.column.chunks, self.local_idx) {
+                (TypedChunks::$variant(arrays), Some(local_idx))
+                    if arrays[self.column.chunk_idx].is_valid(local_idx) =>
+                {
+                    let arr: &'de _ = arrays[self.column.chunk_idx];
+                    visitor.$visit(arr.value(local_idx))
+                }
+                _ => self.fallback().$func(visitor),
+            }
+        }
+    };
+}
+
+impl<'a, 'de> Deserializer<'de> for CursorCell<'a, 'de> {
+    type Error = PlRowSerdeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.is_valid() == Some(false) {
+            return visitor.visit_none();
+        }
+        match self.column.chunks {
+            TypedChunks::Boolean(_) => self.deserialize_bool(visitor),
+            TypedChunks::Int8(_) => self.deserialize_i8(visitor),
+            TypedChunks::Int16(_) => self.deserialize_i16(visitor),
+            TypedChunks::Int32(_) => self.deserialize_i32(visitor),
+            TypedChunks::Int64(_) => self.deserialize_i64(visitor),
+            TypedChunks::UInt8(_) => self.deserialize_u8(visitor),
+            TypedChunks::UInt16(_) => self.deserialize_u16(visitor),
+            TypedChunks::UInt32(_) => self.deserialize_u32(visitor),
+            TypedChunks::UInt64(_) => self.deserialize_u64(visitor),
+            TypedChunks::Float32(_) => self.deserialize_f32(visitor),
+            TypedChunks::Float64(_) => self.deserialize_f64(visitor),
+            TypedChunks::String(_) => self.deserialize_str(visitor),
+            TypedChunks::Binary(_) => self.deserialize_bytes(visitor),
+            TypedChunks::Other => self.fallback().deserialize_any(visitor),
+        }
+    }
+
+    read_typed!(deserialize_bool, Boolean, visit_bool);
+    read_typed!(deserialize_i8, Int8, visit_i8);
+    read_typed!(deserialize_i16, Int16, visit_i16);
+    read_typed!(deserialize_i32, Int32, visit_i32);
+    read_typed!(deserialize_i64, Int64, visit_i64);
+    read_typed!(deserialize_u8, UInt8, visit_u8);
+    read_typed!(deserialize_u16, UInt16, visit_u16);
+    read_typed!(deserialize_u32, UInt32, visit_u32);
+    read_typed!(deserialize_u64, UInt64, visit_u64);
+    read_typed!(deserialize_f32, Float32, visit_f32);
+    read_typed!(deserialize_f64, Float64, visit_f64);
+    read_typed!(deserialize_str, String, visit_borrowed_str);
+    read_typed!(deserialize_string, String, visit_borrowed_str);
+    read_typed!(deserialize_bytes, Binary, visit_borrowed_bytes);
+    read_typed!(deserialize_byte_buf, Binary, visit_borrowed_bytes);
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.is_valid() {
+            Some(true) => visitor.visit_some(self),
+            Some(false) => visitor.visit_none(),
+            None => self.fallback().deserialize_option(visitor),
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_fallback! {
+        deserialize_i128;
+        deserialize_u128;
+        deserialize_char;
+        deserialize_unit;
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq;
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map;
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier;
+    }
+}
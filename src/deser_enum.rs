@@ -0,0 +1,125 @@
+use serde::de::{
+    value::StrDeserializer, DeserializeSeed, EnumAccess, Error, Unexpected, VariantAccess, Visitor,
+};
+use serde::Deserializer;
+
+use crate::{deser_root::SeriesDeserItem, pl_row_error::PlRowSerdeError};
+
+/// Variant of an enum cell and the cell holding its data.
+///
+/// Unit variants are read from String, Categorical and Enum columns and have no
+/// data. Variants with data are read from Struct columns, see
+/// `SeriesDeserItem::struct_variant`.
+pub(crate) struct PlRowEnumAccess<'de> {
+    variant: String,
+    value: Option<SeriesDeserItem<'de>>,
+    /// The one field next to the tag, which holds the fields of a struct
+    /// variant in the adjacent layout.
+    content: Option<SeriesDeserItem<'de>>,
+    row_idx: usize,
+}
+
+impl<'de> PlRowEnumAccess<'de> {
+    pub fn unit(variant: String, row_idx: usize) -> Self {
+        Self {
+            variant,
+            value: None,
+            content: None,
+            row_idx,
+        }
+    }
+
+    pub fn with_value(variant: String, value: SeriesDeserItem<'de>) -> Self {
+        Self {
+            variant,
+            row_idx: value.row_idx,
+            value: Some(value),
+            content: None,
+        }
+    }
+
+    /// Sets the content field a struct variant is read from when it, and not
+    /// the value, has the fields of the variant.
+    pub fn with_content(self, content: Option<SeriesDeserItem<'de>>) -> Self {
+        Self { content, ..self }
+    }
+
+    /// The data of the variant, erroring with `unexpected` for unit variants.
+    fn value(
+        self,
+        unexpected: &'static str,
+    ) -> Result<(String, SeriesDeserItem<'de>), PlRowSerdeError> {
+        match self.value {
+            Some(value) => Ok((self.variant, value)),
+            None => Err(PlRowSerdeError::invalid_type(
+                Unexpected::UnitVariant,
+                &unexpected,
+            )),
+        }
+    }
+}
+
+impl<'de> EnumAccess<'de> for PlRowEnumAccess<'de> {
+    type Error = PlRowSerdeError;
+
+    type Variant = Self;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(StrDeserializer::<PlRowSerdeError>::new(&self.variant))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for PlRowEnumAccess<'de> {
+    type Error = PlRowSerdeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        // whatever marks a unit variant in a struct column carries no data
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        let row_idx = self.row_idx;
+        let (variant, value) = self.value("newtype variant")?;
+        seed.deserialize(value)
+            .map_err(|e| e.within(&variant, row_idx))
+    }
+
+    fn tuple_variant<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let row_idx = self.row_idx;
+        let (variant, value) = self.value("tuple variant")?;
+        value
+            .deserialize_tuple(len, visitor)
+            .map_err(|e| e.within(&variant, row_idx))
+    }
+
+    fn struct_variant<V>(
+        mut self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let row_idx = self.row_idx;
+        let content = self.content.take();
+        let (variant, mut value) = self.value("struct variant")?;
+        if let Some(content) = content {
+            if !value.has_field_in(fields) && content.has_field_in(fields) {
+                value = content;
+            }
+        }
+        value
+            .deserialize_struct("", fields, visitor)
+            .map_err(|e| e.within(&variant, row_idx))
+    }
+}
@@ -1,8 +1,8 @@
-use std::rc::Rc;
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, rc::Rc};
 
 use polars::series::Series;
 use serde::{
-    de::{value::StrDeserializer, IntoDeserializer, MapAccess},
+    de::{value::StrDeserializer, Error, IntoDeserializer, MapAccess, SeqAccess},
     Deserializer,
 };
 
@@ -10,76 +10,166 @@ use crate::{
     deser_root::SeriesDeserItem, deser_series::SeriesDeser, pl_row_error::PlRowSerdeError,
 };
 
-pub(crate) struct PlRowImplMapAccess {
-    pub stack: Rc<Box<[Series]>>,
+/// Columns read by [`PlRowImplMapAccess`].
+///
+/// Columns of the dataframe are borrowed for `'de` so string and binary cells
+/// can be visited without copying. Struct fields that were materialized while
+/// deserializing (e.g. the elements of a list) are shared between rows instead.
+#[derive(Clone, Debug)]
+pub(crate) enum ColumnStack<'de> {
+    Borrowed(&'de [Series]),
+    Shared(Rc<[Series]>),
+}
+
+impl<'de> ColumnStack<'de> {
+    pub fn get(&self, idx: usize) -> Option<Cow<'de, Series>> {
+        match self {
+            Self::Borrowed(stack) => stack.get(idx).map(Cow::Borrowed),
+            Self::Shared(stack) => stack.get(idx).cloned().map(Cow::Owned),
+        }
+    }
+
+    pub fn name(&self, idx: usize) -> Option<&str> {
+        match self {
+            Self::Borrowed(stack) => stack.get(idx).map(|i| i.name()),
+            Self::Shared(stack) => stack.get(idx).map(|i| i.name()),
+        }
+    }
+
+    pub fn position(&self, name: &str) -> Option<usize> {
+        match self {
+            Self::Borrowed(stack) => stack.iter().position(|i| i.name() == name),
+            Self::Shared(stack) => stack.iter().position(|i| i.name() == name),
+        }
+    }
+}
+
+/// The columns a struct reads, resolved from its `fields` by name.
+///
+/// Fields without a column are left out, so serde applies its own
+/// missing-field handling (`Option` becomes `None`, `#[serde(default)]`, ...).
+#[derive(Debug)]
+pub(crate) struct Projection {
+    /// `(field, index of its column)` in declaration order.
+    columns: Box<[(&'static str, usize)]>,
+}
+
+impl Projection {
+    pub fn new(fields: &'static [&'static str], stack: &ColumnStack) -> Self {
+        let columns = fields
+            .iter()
+            .filter_map(|field| stack.position(field).map(|idx| (*field, idx)))
+            .collect();
+        Self { columns }
+    }
+
+    /// The projection of `fields` on `stack`, taken from `projections` when
+    /// it was resolved for an earlier row.
+    pub fn cached(
+        projections: Option<&Projections>,
+        fields: &'static [&'static str],
+        stack: &ColumnStack,
+    ) -> Rc<Self> {
+        match (projections, stack) {
+            (Some(projections), ColumnStack::Borrowed(columns)) => {
+                let key: ProjectionKey = (fields, *columns);
+                let mut cache = projections.0.borrow_mut();
+                let projection = cache
+                    .entry(key)
+                    .or_insert_with(|| Rc::new(Self::new(fields, stack)));
+                Rc::clone(projection)
+            }
+            _ => Rc::new(Self::new(fields, stack)),
+        }
+    }
+}
+
+/// The fields and the columns a projection was resolved against, compared by
+/// address and length.
+type ProjectionKey = (*const [&'static str], *const [Series]);
+
+/// Projections resolved while reading the rows of a frame.
+///
+/// Only columns borrowed from the frame are kept: they stay at the same
+/// address for as long as the frame is borrowed, which the reads sharing
+/// these don't outlive. Columns materialized for a row are new every row.
+#[derive(Debug, Default)]
+pub(crate) struct Projections(RefCell<HashMap<ProjectionKey, Rc<Projection>>>);
+
+pub(crate) struct PlRowImplMapAccess<'de> {
+    pub stack: ColumnStack<'de>,
+    /// Only the projected columns are visited when set, every column otherwise.
+    pub projection: Option<Rc<Projection>>,
+    /// Handed to the cells, see [`Projections`].
+    pub projections: Option<Rc<Projections>>,
     pub map_value_idx: usize,
     pub row_idx: usize,
 }
 
-impl<'de> IntoDeserializer<'de> for PlRowImplMapAccess {
-    type Deserializer = PlRowImplMapAccess;
+impl<'de> IntoDeserializer<'de, PlRowSerdeError> for PlRowImplMapAccess<'de> {
+    type Deserializer = PlRowImplMapAccess<'de>;
 
     fn into_deserializer(self) -> Self::Deserializer {
         self
     }
 }
 
-impl PlRowImplMapAccess {
-    pub(crate) fn new(series_deser: &SeriesDeser) -> Self {
+impl<'de> PlRowImplMapAccess<'de> {
+    pub(crate) fn new(series_deser: &SeriesDeser<'de>) -> Self {
         Self {
-            stack: Rc::new(series_deser.df.get_columns().to_vec().into_boxed_slice()),
+            stack: ColumnStack::Borrowed(series_deser.df.get_columns()),
+            projection: None,
+            projections: series_deser.projections.clone(),
             row_idx: series_deser.row_idx,
             map_value_idx: 0,
         }
     }
 
-    pub fn from_series_vec(stack: Rc<Box<[Series]>>) -> Self {
+    pub fn from_series_vec(stack: ColumnStack<'de>, row_idx: usize) -> Self {
         Self {
             stack,
-            row_idx: 0,
+            projection: None,
+            projections: None,
+            row_idx,
             map_value_idx: 0,
         }
     }
-}
-
-impl<'de> MapAccess<'de> for PlRowImplMapAccess {
-    type Error = PlRowSerdeError;
 
-    fn next_key<K>(&mut self) -> Result<Option<K>, Self::Error>
-    where
-        K: serde::Deserialize<'de>,
-    {
-        match self.stack.get(self.map_value_idx) {
-            None => Ok(None),
-            Some(got) => K::deserialize(StrDeserializer::new(got.name())).map(|i| Some(i)),
-        }
+    pub fn with_projection(mut self, projection: Rc<Projection>) -> Self {
+        self.projection = Some(projection);
+        self
     }
 
-    fn next_value<V>(&mut self) -> Result<V, Self::Error>
-    where
-        V: serde::Deserialize<'de>,
-    {
-        let _map_value_idx = self.map_value_idx;
-        self.map_value_idx += 1;
-
-        let item = SeriesDeserItem {
-            row_idx: self.row_idx,
-            // this always succeed because the value exists at next_key
-            series: self.stack[_map_value_idx].clone(),
-        };
+    pub fn with_projections(mut self, projections: Option<Rc<Projections>>) -> Self {
+        self.projections = projections;
+        self
+    }
 
-        V::deserialize(item)
+    /// Key and column index of the entry at `map_value_idx`.
+    fn entry(&self) -> Option<(&str, usize)> {
+        match &self.projection {
+            Some(projection) => projection
+                .columns
+                .get(self.map_value_idx)
+                .map(|(field, idx)| (*field, *idx)),
+            None => self
+                .stack
+                .name(self.map_value_idx)
+                .map(|name| (name, self.map_value_idx)),
+        }
     }
+}
+
+impl<'de> MapAccess<'de> for PlRowImplMapAccess<'de> {
+    type Error = PlRowSerdeError;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
     where
         K: serde::de::DeserializeSeed<'de>,
     {
-        match self.stack.get(self.map_value_idx) {
+        match self.entry() {
             None => Ok(None),
-            Some(got) => seed
-                .deserialize(StrDeserializer::new(got.name()))
-                .map(|i| Some(i)),
+            Some((got, _)) => seed.deserialize(StrDeserializer::new(got)).map(|i| Some(i)),
         }
     }
 
@@ -87,247 +177,129 @@ impl<'de> MapAccess<'de> for PlRowImplMapAccess {
     where
         V: serde::de::DeserializeSeed<'de>,
     {
-        let _map_value_idx = self.map_value_idx;
+        let Some((_, column_idx)) = self.entry() else {
+            return Err(PlRowSerdeError::custom("value requested without a key"));
+        };
+        let Some(series) = self.stack.get(column_idx) else {
+            return Err(PlRowSerdeError::custom("value requested without a key"));
+        };
         self.map_value_idx += 1;
 
         let item = SeriesDeserItem {
             row_idx: self.row_idx,
-            // this always succeed because the value exists at next_key
-            series: self.stack[_map_value_idx].clone(),
+            series,
+            projections: self.projections.clone(),
         };
 
-        seed.deserialize(item)
-    }
-}
-
-impl<'de> Deserializer<'de> for PlRowImplMapAccess {
-    // only function to implement
-    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        visitor.visit_map(self)
-    }
-
-    type Error = PlRowSerdeError;
-
-    // all the other stuff
-    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_bool<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i8<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i16<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i32<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i64<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u8<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u16<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u32<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u64<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_f32<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_f64<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_char<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+        seed.deserialize(item).map_err(|e| {
+            let name = self.stack.name(column_idx).unwrap_or_default();
+            e.within(name, self.row_idx)
+        })
     }
 
-    fn deserialize_str<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+    fn size_hint(&self) -> Option<usize> {
+        match &self.projection {
+            Some(projection) => Some(projection.columns.len() - self.map_value_idx),
+            None => None,
+        }
     }
+}
 
-    fn deserialize_string<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
+/// Reads the cells of a row by column position, for tuple rows.
+///
+/// Columns past `len` are ignored; fewer columns than `len` are reported by
+/// the visitor as an invalid length.
+pub(crate) struct PlRowImplSeqAccess<'de> {
+    stack: ColumnStack<'de>,
+    projections: Option<Rc<Projections>>,
+    column_idx: usize,
+    len: usize,
+    row_idx: usize,
+}
 
-    fn deserialize_bytes<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+impl<'de> PlRowImplSeqAccess<'de> {
+    pub fn new(stack: ColumnStack<'de>, len: usize, row_idx: usize) -> Self {
+        Self {
+            stack,
+            projections: None,
+            column_idx: 0,
+            len,
+            row_idx,
+        }
     }
 
-    fn deserialize_byte_buf<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+    pub fn with_projections(mut self, projections: Option<Rc<Projections>>) -> Self {
+        self.projections = projections;
+        self
     }
+}
 
-    fn deserialize_option<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
+impl<'de> SeqAccess<'de> for PlRowImplSeqAccess<'de> {
+    type Error = PlRowSerdeError;
 
-    fn deserialize_unit<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
     where
-        V: serde::de::Visitor<'de>,
+        T: serde::de::DeserializeSeed<'de>,
     {
-        todo!()
-    }
+        if self.column_idx >= self.len {
+            return Ok(None);
+        }
+        let column_idx = self.column_idx;
+        let Some(series) = self.stack.get(column_idx) else {
+            return Ok(None);
+        };
+        self.column_idx += 1;
 
-    fn deserialize_unit_struct<V>(
-        self,
-        _name: &'static str,
-        _visitor: V,
-    ) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+        let item = SeriesDeserItem {
+            row_idx: self.row_idx,
+            series,
+            projections: self.projections.clone(),
+        };
+        seed.deserialize(item).map(Some).map_err(|e| {
+            let name = self.stack.name(column_idx).unwrap_or_default();
+            e.within(name, self.row_idx)
+        })
     }
 
-    fn deserialize_newtype_struct<V>(
-        self,
-        _name: &'static str,
-        _visitor: V,
-    ) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.column_idx)
     }
+}
 
-    fn deserialize_seq<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
+impl<'de> Deserializer<'de> for PlRowImplMapAccess<'de> {
+    type Error = PlRowSerdeError;
 
-    fn deserialize_tuple<V>(self, _len: usize, _visitor: V) -> Result<V::Value, Self::Error>
+    // a map access can only ever be read as a map
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_map(self)
     }
 
-    fn deserialize_tuple_struct<V>(
-        self,
-        _name: &'static str,
-        _len: usize,
-        _visitor: V,
-    ) -> Result<V::Value, Self::Error>
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_some(self)
     }
 
     fn deserialize_struct<V>(
         self,
         _name: &'static str,
-        _fields: &'static [&'static str],
-        _visitor: V,
+        fields: &'static [&'static str],
+        visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        let projection = Projection::cached(self.projections.as_deref(), fields, &self.stack);
+        visitor.visit_map(self.with_projection(projection))
     }
 
-    fn deserialize_enum<V>(
-        self,
-        _name: &'static str,
-        _variants: &'static [&'static str],
-        _visitor: V,
-    ) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_identifier<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_ignored_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
     }
 }
@@ -1,270 +1,551 @@
-use std::{collections::HashSet, rc::Rc};
+use std::{borrow::Cow, collections::HashSet, rc::Rc, str::FromStr};
 
 use polars::{
-    datatypes::{AnyValue, DataType, LogicalType},
+    datatypes::{AnyValue, DataType, LogicalType, TimeUnit},
+    export::{
+        arrow::temporal_conversions::{
+            date32_to_date_opt, time64ns_to_time_opt, timestamp_ms_to_datetime_opt,
+            timestamp_ns_to_datetime_opt, timestamp_us_to_datetime_opt,
+        },
+        chrono::{FixedOffset, TimeZone},
+    },
     prelude::NamedFrom,
     series::Series,
 };
 use serde::{
     de::{
-        value::{SeqDeserializer, StrDeserializer},
-        Error, IntoDeserializer, Visitor,
+        value::{MapDeserializer, SeqDeserializer},
+        Error, IntoDeserializer, Unexpected, Visitor,
     },
     Deserializer,
 };
 
 use crate::{
-    deser_map::PlRowImplMapAccess, deser_seq::ChunkedArrayDeserializer,
+    deser_enum::PlRowEnumAccess,
+    deser_map::{ColumnStack, PlRowImplMapAccess, PlRowImplSeqAccess, Projection, Projections},
+    deser_seq::ChunkedArrayDeserializer,
     pl_row_error::PlRowSerdeError,
+    ser_builder::VALIDITY,
+    ser_root::{nanos_per_tick, DURATION},
 };
 
+/// Deserializer for a single cell.
+///
+/// `series` is borrowed when it is a column of the dataframe (or a field of a
+/// struct column), so strings and bytes are visited as `'de` borrows. Series
+/// materialized along the way, like list elements, are owned.
 #[derive(Debug)]
-pub(crate) struct SeriesDeserItem {
-    pub series: Series,
+pub(crate) struct SeriesDeserItem<'de> {
+    pub series: Cow<'de, Series>,
     pub row_idx: usize,
+    /// The projections of the rows read so far, see [`Projections`].
+    pub projections: Option<Rc<Projections>>,
 }
 
-impl<'de> IntoDeserializer<'de> for SeriesDeserItem {
-    type Deserializer = SeriesDeserItem;
+impl<'de> IntoDeserializer<'de, PlRowSerdeError> for SeriesDeserItem<'de> {
+    type Deserializer = SeriesDeserItem<'de>;
 
     fn into_deserializer(self) -> Self::Deserializer {
         self
     }
 }
 
-impl SeriesDeserItem {
-    fn maybe_list_or_bytes<'de, V>(self, visitor: V) -> Result<V::Value, PlRowSerdeError>
-    where
-        V: Visitor<'de>,
-    {
-        match self.series.dtype() {
-            DataType::BinaryOffset | DataType::Binary => {
-                let res = self.series.binary();
-                if res.is_err() {
-                    unreachable!();
-                }
-                self.series
-                    .binary()
-                    .map_err(|e| PlRowSerdeError::custom(e))?
-                    .get(self.row_idx)
-                    .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                    .and_then(|i| visitor.visit_bytes(i))
+/// Reads the cell with `$cast` and hands it to the visitor, borrowed for `'de`
+/// when the series is.
+macro_rules! visit_maybe_borrowed {
+    ($self: ident, $visitor: ident, $cast: ident, $expected: literal, $visit_borrowed: ident, $visit: ident) => {
+        match $self.series {
+            Cow::Borrowed(series) => match series.$cast() {
+                Ok(i) => match i.get($self.row_idx) {
+                    Some(i) => $visitor.$visit_borrowed(i),
+                    None => Err(missing_value(series, $self.row_idx, $expected)),
+                },
+                Err(_) => Err(PlRowSerdeError::type_mismatch($expected, series.dtype())),
+            },
+            Cow::Owned(series) => match series.$cast() {
+                Ok(i) => match i.get($self.row_idx) {
+                    Some(i) => $visitor.$visit(i),
+                    None => Err(missing_value(&series, $self.row_idx, $expected)),
+                },
+                Err(_) => Err(PlRowSerdeError::type_mismatch($expected, series.dtype())),
+            },
+        }
+    };
+}
+
+/// Reads the cell with `$cast`, the name of the rust type it returns.
+macro_rules! deserialize_primitive {
+    ($func: ident, $cast: ident, $visit: ident) => {
+        fn $func<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            match self.series.$cast() {
+                Ok(i) => match i.get(self.row_idx) {
+                    Some(i) => visitor.$visit(i),
+                    None => Err(self.missing(stringify!($cast))),
+                },
+                Err(_) => Err(self.mismatch(stringify!($cast))),
             }
-            DataType::List(_) => self.deserialize_seq(visitor),
-            _ => unimplemented!(),
         }
-    }
+    };
 }
 
-impl<'de> Deserializer<'de> for SeriesDeserItem {
-    type Error = PlRowSerdeError;
+impl<'de> SeriesDeserItem<'de> {
+    /// Fields of a struct column, borrowed for `'de` when the column is.
+    fn struct_fields(&self) -> Result<ColumnStack<'de>, PlRowSerdeError> {
+        match &self.series {
+            Cow::Borrowed(series) => {
+                let series: &'de Series = series;
+                series
+                    .struct_()
+                    .map(|s| ColumnStack::Borrowed(visible_fields(s.fields())))
+            }
+            Cow::Owned(series) => series
+                .struct_()
+                .map(|s| ColumnStack::Shared(visible_fields(s.fields()).into())),
+        }
+        .map_err(|_| self.mismatch("struct"))
+    }
 
-    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        // try date
-        unimplemented!()
+    /// Category of a Categorical or Enum cell, `None` when the cell is null.
+    fn category(&self) -> Result<Option<&str>, PlRowSerdeError> {
+        let cat = self
+            .series
+            .categorical()
+            .map_err(|_| self.mismatch("str"))?;
+        Ok(cat
+            .physical()
+            .get(self.row_idx)
+            .map(|idx| cat.get_rev_map().get(idx)))
     }
 
-    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        let res = self.series.bool();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_bool(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
-        }
+    /// The cells of the fields of a struct column.
+    fn field_items(&self) -> Result<Vec<SeriesDeserItem<'de>>, PlRowSerdeError> {
+        let stack = self.struct_fields()?;
+        Ok((0..)
+            .map_while(|idx| stack.get(idx))
+            .map(|series| SeriesDeserItem {
+                series,
+                row_idx: self.row_idx,
+                projections: self.projections.clone(),
+            })
+            .collect())
     }
 
-    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        let res = self.series.i8();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_i8(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
-        }
+    /// The cell of a String, Categorical or Enum column when it names one of
+    /// `variants`.
+    fn variant_name(&self, variants: &[&str]) -> Option<&str> {
+        let name = match self.series.dtype() {
+            DataType::String => self.series.str().ok()?.get(self.row_idx),
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => self.category().ok()?,
+            _ => None,
+        }?;
+        variants.contains(&name).then_some(name)
     }
 
-    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        let res = self.series.i16();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_i16(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+    /// The variant of a Struct cell written with one of the [`EnumLayout`]s.
+    ///
+    /// The tag is a field that isn't named after a variant but holds one. The
+    /// payload of newtype and tuple variants is the field named after the
+    /// variant, next to the tag or in the content field of the adjacent
+    /// layout. Struct variants have their fields in the struct itself in the
+    /// internal layout and in the content field in the adjacent one, told
+    /// apart by the names of the fields once they are known, see
+    /// [`PlRowEnumAccess::with_content`]. Without a tag, the variant is the
+    /// field named after a variant set in the row.
+    ///
+    /// [`EnumLayout`]: crate::EnumLayout
+    fn struct_variant(self, variants: &[&str]) -> Result<PlRowEnumAccess<'de>, PlRowSerdeError> {
+        let mut fields = self.field_items()?;
+        let is_variant = |item: &SeriesDeserItem| variants.contains(&item.series.name());
+        let tag = fields
+            .iter()
+            .position(|i| !is_variant(i) && i.variant_name(variants).is_some());
+        let Some(tag) = tag else {
+            return match fields
+                .into_iter()
+                .find(|i| is_variant(i) && !i.cell_is_null())
+            {
+                Some(value) => Ok(PlRowEnumAccess::with_value(
+                    value.series.name().to_string(),
+                    value,
+                )),
+                None => Err(self.missing("enum")),
+            };
+        };
+        let tag = fields.remove(tag);
+        let variant = tag.variant_name(variants).unwrap_or_default().to_string();
+
+        if let Some(idx) = fields.iter().position(|i| i.series.name() == variant) {
+            let value = fields.remove(idx);
+            return Ok(PlRowEnumAccess::with_value(variant, value));
+        }
+        let content = (fields.len() == 1 && !is_variant(&fields[0])).then(|| fields.remove(0));
+        let value = content
+            .as_ref()
+            .and_then(|i| i.field_items().ok())
+            .and_then(|fields| fields.into_iter().find(|i| i.series.name() == variant));
+        if let Some(value) = value {
+            return Ok(PlRowEnumAccess::with_value(variant, value));
         }
+        Ok(PlRowEnumAccess::with_value(variant, self).with_content(content))
+    }
+
+    /// Whether the cell is a struct with a field named one of `names`.
+    pub(crate) fn has_field_in(&self, names: &[&str]) -> bool {
+        self.series.struct_().map_or(false, |s| {
+            s.fields().iter().any(|i| names.contains(&i.name()))
+        })
+    }
+
+    fn cell_is_null(&self) -> bool {
+        is_null_at(&self.series, self.row_idx)
+    }
+
+    /// Error for a cell that can't be read as `expected`.
+    fn mismatch(&self, expected: &'static str) -> PlRowSerdeError {
+        PlRowSerdeError::type_mismatch(expected, self.series.dtype())
+    }
+
+    /// Error for a cell that has no value.
+    fn missing(&self, expected: &'static str) -> PlRowSerdeError {
+        missing_value(&self.series, self.row_idx, expected)
+    }
+
+    /// Elements of an Array cell, `None` when the cell is null.
+    fn array_elements(&self) -> Result<Option<Series>, PlRowSerdeError> {
+        let DataType::Array(inner, _) = self.series.dtype() else {
+            return Err(self.mismatch("array"));
+        };
+        let cell = self
+            .series
+            .slice(self.row_idx as i64, 1)
+            .cast(&DataType::List(inner.clone()))?;
+        Ok(cell.list()?.get_as_series(0))
     }
 
-    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    /// Visit every value of `inner` as an element of a sequence.
+    fn visit_elements<V>(inner: Option<Series>, visitor: V) -> Result<V::Value, PlRowSerdeError>
     where
-        V: serde::de::Visitor<'de>,
+        V: Visitor<'de>,
     {
-        let res = self.series.i32();
+        let inner = inner.unwrap_or_else(|| Series::new_empty("", &DataType::Null));
+        let len = inner.len();
+        let iter = (0..len).map(move |row_idx| {
+            Some(SeriesDeserItem {
+                series: Cow::Owned(inner.clone()),
+                row_idx,
+                projections: None,
+            })
+        });
+        visitor.visit_seq(ChunkedArrayDeserializer::new(iter, len))
+    }
 
-        match res {
-            Ok(i) => i
+    /// The cell of a Decimal, String or Binary column holding an `i128` or
+    /// `u128`, `None` for other columns.
+    fn int128_cell(&self, expected: &'static str) -> Result<Option<Int128Cell>, PlRowSerdeError> {
+        let cell = match self.series.dtype() {
+            DataType::Decimal(_, _) => self
+                .series
+                .decimal()?
                 .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_i32(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+                .map(Int128Cell::Unscaled),
+            DataType::String => self.series.str()?.get(self.row_idx).map(Int128Cell::Digits),
+            DataType::Binary => match self.series.binary()?.get(self.row_idx) {
+                Some(v) => match v.try_into() {
+                    Ok(v) => Some(Int128Cell::Bytes(v)),
+                    Err(_) => return Err(PlRowSerdeError::invalid_length(v.len(), &"16 bytes")),
+                },
+                None => None,
+            },
+            _ => return Ok(None),
+        };
+        match cell {
+            Some(cell) => Ok(Some(cell)),
+            None => Err(self.missing(expected)),
         }
     }
 
-    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        let res = self.series.i64();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_i64(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+    /// The cell of a String, Categorical, Enum or UTF-8 Binary column, `None`
+    /// when it is null.
+    fn text(&self, expected: &'static str) -> Result<Option<&str>, PlRowSerdeError> {
+        match self.series.dtype() {
+            DataType::String => Ok(self.series.str()?.get(self.row_idx)),
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => self.category(),
+            DataType::Binary | DataType::BinaryOffset => {
+                binary_at(&self.series, self.row_idx)?.map(utf8).transpose()
+            }
+            _ => Err(self.mismatch(expected)),
+        }
+    }
+
+    /// Ticks of a Duration cell and their time unit.
+    fn duration_ticks(&self) -> Result<(i64, TimeUnit), PlRowSerdeError> {
+        let DataType::Duration(unit) = self.series.dtype() else {
+            return Err(self.mismatch("duration"));
+        };
+        match self.series.duration()?.get(self.row_idx) {
+            Some(ticks) => Ok((ticks, *unit)),
+            None => Err(self.missing("duration")),
         }
     }
 
-    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    /// Visit a Date, Datetime or Time cell as its ISO 8601 string.
+    fn visit_temporal<V>(self, visitor: V) -> Result<V::Value, PlRowSerdeError>
     where
-        V: serde::de::Visitor<'de>,
+        V: Visitor<'de>,
     {
-        let res = self.series.u8();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_u8(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        let physical = match self.series.dtype() {
+            DataType::Date => self.series.date()?.get(self.row_idx).map(i64::from),
+            DataType::Datetime(_, _) => self.series.datetime()?.get(self.row_idx),
+            DataType::Time => self.series.time()?.get(self.row_idx),
+            _ => return Err(self.mismatch("str")),
+        };
+        match physical {
+            Some(v) => match temporal_to_string(self.series.dtype(), v) {
+                Some(s) => visitor.visit_string(s),
+                None => Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Signed(v),
+                    &"a value in the range of the temporal dtype",
+                )),
+            },
+            None => Err(self.missing("str")),
         }
     }
 
-    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    /// Visit the `{key, value}` structs of `entries` as the entries of a map.
+    ///
+    /// A map column that is empty in every row has Null entries, which are
+    /// read as an empty map.
+    fn visit_entries<V>(entries: Series, visitor: V) -> Result<V::Value, PlRowSerdeError>
     where
-        V: serde::de::Visitor<'de>,
+        V: Visitor<'de>,
     {
-        let res = self.series.u16();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_u16(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        if entries.dtype() == &DataType::Null && entries.is_empty() {
+            let empty = std::iter::empty::<((), ())>();
+            return visitor.visit_map(MapDeserializer::<_, PlRowSerdeError>::new(empty));
         }
+        let fields = entries.struct_()?.fields();
+        let field = |name: &'static str| match fields.iter().find(|i| i.name() == name) {
+            Some(field) => Ok(field.clone()),
+            None => Err(PlRowSerdeError::missing_field(name)),
+        };
+        let (keys, values) = (field("key")?, field("value")?);
+        let iter = (0..entries.len()).map(move |row_idx| {
+            let key = SeriesDeserItem {
+                series: Cow::Owned(keys.clone()),
+                row_idx,
+                projections: None,
+            };
+            let value = SeriesDeserItem {
+                series: Cow::Owned(values.clone()),
+                row_idx,
+                projections: None,
+            };
+            (key, value)
+        });
+        let mut map = MapDeserializer::<_, PlRowSerdeError>::new(iter);
+        let value = visitor.visit_map(&mut map)?;
+        map.end()?;
+        Ok(value)
     }
+}
+
+impl<'de> Deserializer<'de> for SeriesDeserItem<'de> {
+    type Error = PlRowSerdeError;
 
-    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.u32();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_u32(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        if self.cell_is_null() {
+            return visitor.visit_none();
+        }
+
+        match self.series.dtype() {
+            // PRIMITIVE START
+            DataType::Boolean => self.deserialize_bool(visitor),
+            DataType::UInt8 => self.deserialize_u8(visitor),
+            DataType::UInt16 => self.deserialize_u16(visitor),
+            DataType::UInt32 => self.deserialize_u32(visitor),
+            DataType::UInt64 => self.deserialize_u64(visitor),
+            DataType::Int8 => self.deserialize_i8(visitor),
+            DataType::Int16 => self.deserialize_i16(visitor),
+            DataType::Int32 => self.deserialize_i32(visitor),
+            DataType::Int64 => self.deserialize_i64(visitor),
+            DataType::Float32 => self.deserialize_f32(visitor),
+            DataType::Float64 => self.deserialize_f64(visitor),
+            // END PRIMITIVE
+            DataType::String => self.deserialize_str(visitor),
+            DataType::Binary | DataType::BinaryOffset => self.deserialize_bytes(visitor),
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => match self.category()? {
+                Some(category) => visitor.visit_str(category),
+                None => visitor.visit_none(),
+            },
+            // temporal values are handed out as ISO 8601 strings
+            DataType::Date | DataType::Datetime(_, _) | DataType::Time => {
+                self.visit_temporal(visitor)
+            }
+            // durations are handed out as ticks of their time unit
+            DataType::Duration(_) => visitor.visit_i64(self.duration_ticks()?.0),
+            // decimals are handed out as strings so no precision is lost
+            DataType::Decimal(_, _) => match self.series.get(self.row_idx) {
+                Ok(v) => visitor.visit_string(v.to_string()),
+                Err(_) => Err(self.missing("str")),
+            },
+            DataType::List(_) => match self.series.list() {
+                Ok(i) => Self::visit_elements(i.get_as_series(self.row_idx), visitor),
+                Err(_) => Err(self.mismatch("seq")),
+            },
+            DataType::Array(_, _) => match self.series.array() {
+                Ok(i) => Self::visit_elements(i.get_as_series(self.row_idx), visitor),
+                Err(_) => Err(self.mismatch("seq")),
+            },
+            DataType::Struct(_) => visitor.visit_map(
+                PlRowImplMapAccess::from_series_vec(self.struct_fields()?, self.row_idx)
+                    .with_projections(self.projections.clone()),
+            ),
+            DataType::Null => visitor.visit_unit(),
+            dtype @ (DataType::Object(_, _) | DataType::Unknown(_)) => {
+                Err(PlRowSerdeError::unsupported(dtype))
+            }
         }
     }
 
-    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    deserialize_primitive!(deserialize_bool, bool, visit_bool);
+
+    deserialize_primitive!(deserialize_i8, i8, visit_i8);
+
+    deserialize_primitive!(deserialize_i16, i16, visit_i16);
+
+    deserialize_primitive!(deserialize_i32, i32, visit_i32);
+
+    /// Duration cells are read as ticks of their time unit.
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.u64();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_u64(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        if let DataType::Duration(_) = self.series.dtype() {
+            return visitor.visit_i64(self.duration_ticks()?.0);
+        }
+        match self.series.i64() {
+            Ok(i) => match i.get(self.row_idx) {
+                Some(i) => visitor.visit_i64(i),
+                None => Err(self.missing("i64")),
+            },
+            Err(_) => Err(self.mismatch("i64")),
         }
     }
 
-    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    /// Decimal cells are read as their unscaled value, String and Binary ones
+    /// as written with [`Int128Layout`](crate::Int128Layout).
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.f32();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_f32(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        match self.int128_cell("i128")? {
+            Some(Int128Cell::Unscaled(v)) => visitor.visit_i128(v),
+            Some(Int128Cell::Digits(v)) => match v.parse() {
+                Ok(v) => visitor.visit_i128(v),
+                Err(_) => Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Str(v),
+                    &"an i128",
+                )),
+            },
+            Some(Int128Cell::Bytes(v)) => visitor.visit_i128(i128::from_be_bytes(v)),
+            None => self.deserialize_any(visitor),
         }
     }
 
-    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.f64();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_f64(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        match self.int128_cell("u128")? {
+            Some(Int128Cell::Unscaled(v)) => match u128::try_from(v) {
+                Ok(v) => visitor.visit_u128(v),
+                Err(_) => Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Other("a negative decimal"),
+                    &"a u128",
+                )),
+            },
+            Some(Int128Cell::Digits(v)) => match v.parse() {
+                Ok(v) => visitor.visit_u128(v),
+                Err(_) => Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Str(v),
+                    &"a u128",
+                )),
+            },
+            Some(Int128Cell::Bytes(v)) => visitor.visit_u128(u128::from_be_bytes(v)),
+            None => self.deserialize_any(visitor),
         }
     }
 
+    deserialize_primitive!(deserialize_u8, u8, visit_u8);
+
+    deserialize_primitive!(deserialize_u16, u16, visit_u16);
+
+    deserialize_primitive!(deserialize_u32, u32, visit_u32);
+
+    deserialize_primitive!(deserialize_u64, u64, visit_u64);
+
+    deserialize_primitive!(deserialize_f32, f32, visit_f32);
+
+    deserialize_primitive!(deserialize_f64, f64, visit_f64);
+
+    /// Chars are read from UInt8 columns as bytes, or from text cells holding
+    /// a single character, see [`SeriesDeserItem::text`].
     fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        // try str
-
-        let c = 'c: {
-            let s_u8 = self.series.u8();
-            let opt_c = s_u8.map(|i| i.get(self.row_idx).map(|i| i as char));
-            if let Ok(Some(c)) = opt_c {
-                break 'c c;
-            }
-
-            let res = self.series.str().map(|i| i.get(self.row_idx));
-            match res {
-                Ok(Some(i)) if i.len() == 1 => {
-                    if let Some(c) = i.as_bytes().get(0).map(|i| *i as char) {
-                        break 'c c;
-                    };
-                }
-                Ok(None) | Ok(Some(_)) => {
-                    return Err(PlRowSerdeError::custom(source_code_location::new_string!()))
-                }
-                Err(e) => return Err(PlRowSerdeError::custom(e)),
+        if let DataType::UInt8 = self.series.dtype() {
+            return match self.series.u8()?.get(self.row_idx) {
+                Some(i) => visitor.visit_char(i as char),
+                None => Err(self.missing("char")),
             };
-
-            return Err(PlRowSerdeError::custom("msg"));
+        }
+        let Some(text) = self.text("char")? else {
+            return Err(self.missing("char"));
         };
-
-        visitor.visit_char(c)
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(PlRowSerdeError::invalid_value(
+                Unexpected::Str(text),
+                &"a single character",
+            )),
+        }
     }
 
     fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.str();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_str(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+        match self.series.dtype() {
+            // e.g. chrono values
+            DataType::Date | DataType::Datetime(_, _) | DataType::Time => {
+                self.visit_temporal(visitor)
+            }
+            DataType::Binary | DataType::BinaryOffset => {
+                let row_idx = self.row_idx;
+                match self.series {
+                    Cow::Borrowed(series) => match binary_at(series, row_idx)? {
+                        Some(v) => visitor.visit_borrowed_str(utf8(v)?),
+                        None => Err(missing_value(series, row_idx, "str")),
+                    },
+                    Cow::Owned(_) => match self.text("str")? {
+                        Some(v) => visitor.visit_str(v),
+                        None => Err(self.missing("str")),
+                    },
+                }
+            }
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => match self.category()? {
+                Some(v) => visitor.visit_str(v),
+                None => Err(self.missing("str")),
+            },
+            _ => visit_maybe_borrowed!(self, visitor, str, "str", visit_borrowed_str, visit_str),
         }
     }
 
@@ -280,20 +561,43 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
         V: serde::de::Visitor<'de>,
     {
         match self.series.dtype() {
-            DataType::BinaryOffset | DataType::Binary => {
-                let res = self.series.binary();
-                if res.is_err() {
-                    unreachable!();
+            DataType::Binary => {
+                visit_maybe_borrowed!(
+                    self,
+                    visitor,
+                    binary,
+                    "bytes",
+                    visit_borrowed_bytes,
+                    visit_bytes
+                )
+            }
+            DataType::BinaryOffset => visit_maybe_borrowed!(
+                self,
+                visitor,
+                binary_offset,
+                "bytes",
+                visit_borrowed_bytes,
+                visit_bytes
+            ),
+            DataType::String => {
+                let row_idx = self.row_idx;
+                match self.series {
+                    Cow::Borrowed(series) => match series.str()?.get(row_idx) {
+                        Some(v) => visitor.visit_borrowed_bytes(v.as_bytes()),
+                        None => Err(missing_value(series, row_idx, "bytes")),
+                    },
+                    Cow::Owned(_) => match self.text("bytes")? {
+                        Some(v) => visitor.visit_bytes(v.as_bytes()),
+                        None => Err(self.missing("bytes")),
+                    },
                 }
-                self.series
-                    .binary()
-                    .map_err(|e| PlRowSerdeError::custom(e))?
-                    .get(self.row_idx)
-                    .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                    .and_then(|i| visitor.visit_bytes(i))
             }
-            DataType::List(_) => self.deserialize_seq(visitor),
-            _ => unimplemented!(),
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => match self.category()? {
+                Some(v) => visitor.visit_bytes(v.as_bytes()),
+                None => Err(self.missing("bytes")),
+            },
+            DataType::List(_) | DataType::Array(_, _) => self.deserialize_seq(visitor),
+            _ => Err(self.mismatch("bytes")),
         }
     }
 
@@ -301,14 +605,7 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        let res = self.series.binary();
-        match res {
-            Ok(i) => i
-                .get(self.row_idx)
-                .ok_or_else(|| PlRowSerdeError::custom(source_code_location::new_string!()))
-                .and_then(|i| visitor.visit_bytes(i)),
-            Err(e) => Err(PlRowSerdeError::custom(e)),
-        }
+        self.deserialize_bytes(visitor)
     }
 
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -319,34 +616,30 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
             return visitor.visit_none();
         };
 
-        let nul_c = self.series.null_count() > 0;
-        if nul_c {
-            match self.series.get(self.row_idx) {
-                Ok(AnyValue::Null) => visitor.visit_none(),
-                Ok(_) => visitor.visit_some(self),
-                Err(e) => Err(PlRowSerdeError::custom(e)),
-            }
+        if self.cell_is_null() {
+            visitor.visit_none()
         } else {
             visitor.visit_some(self)
         }
     }
 
+    /// Units carry no data, whatever the cell holds.
     fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_bool(true)
+        visitor.visit_unit()
     }
 
     fn deserialize_unit_struct<V>(
         self,
-        name: &'static str,
+        _name: &'static str,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_str(name)
+        visitor.visit_unit()
     }
 
     fn deserialize_newtype_struct<V>(
@@ -357,7 +650,18 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_str(name)
+        // durations wrapped by the `temporal` module, as nanoseconds
+        if let (DURATION, DataType::Duration(_)) = (name, self.series.dtype()) {
+            let (ticks, unit) = self.duration_ticks()?;
+            return match ticks.checked_mul(nanos_per_tick(unit)) {
+                Some(nanos) => visitor.visit_i64(nanos),
+                None => Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Signed(ticks),
+                    &"a duration in the range of i64 nanoseconds",
+                )),
+            };
+        }
+        visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -369,8 +673,7 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
                 if let Some(seq) = $chunked_array.get(self.row_idx) {
                     visitor.visit_seq(SeqDeserializer::new(seq.into_iter().map(|i| *i)))
                 } else {
-                    let arr: [u8; 0] = [];
-                    visitor.visit_seq(SeqDeserializer::new(arr.into_iter()))
+                    Err(self.missing("seq"))
                 }
             };
         }
@@ -398,7 +701,7 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
                 let res = if let Some(seq) = chunked_array.get(self.row_idx) {
                     visitor.visit_seq(SeqDeserializer::new(seq.as_bytes().into_iter().map(|i| *i)))
                 } else {
-                    return Err(PlRowSerdeError::custom(source_code_location::new_string!()));
+                    return Err(self.missing("seq"));
                 };
                 return res;
             }
@@ -406,14 +709,31 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
             Err(_) => (),
         };
 
+        // and categories, e.g. into a `Box<[u8]>`
+        if let DataType::Categorical(_, _) | DataType::Enum(_, _) = self.series.dtype() {
+            return match self.category()? {
+                Some(v) => visitor.visit_seq(SeqDeserializer::new(v.bytes())),
+                None => Err(self.missing("seq")),
+            };
+        }
+
+        if let DataType::Array(_, _) = self.series.dtype() {
+            return match self.array_elements()? {
+                Some(elements) => Self::visit_elements(Some(elements), visitor),
+                None => Err(self.missing("seq")),
+            };
+        }
+
         let res = self.series.list();
         match res {
             Ok(i) => {
-                let series = i.get_as_series(self.row_idx).unwrap();
+                let Some(series) = i.get_as_series(self.row_idx) else {
+                    return Err(self.missing("seq"));
+                };
                 macro_rules! template {
                     ($dt: ident, $series: ident) => {
                         visitor.visit_seq(ChunkedArrayDeserializer::new(
-                            $series.$dt().unwrap().iter(),
+                            $series.$dt()?.iter(),
                             $series.len(),
                         ))
                     };
@@ -422,7 +742,7 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
                 macro_rules! template_time_stuff {
                     ($ser_func: ident, $series: ident) => {
                         visitor.visit_seq({
-                            let dt = $series.$ser_func().unwrap();
+                            let dt = $series.$ser_func()?;
                             let seq = ChunkedArrayDeserializer::new(dt.into_iter(), dt.len());
                             seq
                         })
@@ -451,70 +771,47 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
                     DataType::Datetime(_, _) => template_time_stuff!(datetime, series),
                     DataType::Duration(_) => template_time_stuff!(duration, series),
                     DataType::Time => template_time_stuff!(time, series),
-                    DataType::Array(_, size) => {
-                        let iter = series
-                            .array()
-                            .unwrap()
-                            .into_iter()
-                            .map(|opt_ser| {
-                                (0..series.len()).into_iter().map(move |row_idx| {
-                                    if let Some(series) = opt_ser.as_ref() {
-                                        Some(SeriesDeserItem {
-                                            series: series.clone(),
-                                            row_idx,
-                                        })
-                                    } else {
-                                        None
-                                    }
-                                })
+                    // every element is a List or Array cell of its own
+                    DataType::List(_) | DataType::Array(_, _) => {
+                        let iter = (0..series.len()).map(|row_idx| {
+                            (!is_null_at(&series, row_idx)).then(|| SeriesDeserItem {
+                                series: Cow::Owned(series.clone()),
+                                row_idx,
+                                projections: None,
                             })
-                            .flatten();
-
-                        visitor.visit_seq(ChunkedArrayDeserializer::new(iter, *size))
-                    }
-                    DataType::List(_) => {
-                        let c = ChunkedArrayDeserializer::new(
-                            series.list().unwrap().into_iter().map(|i| match i {
-                                None => None,
-                                Some(series) => Some(SeriesDeserItem { series, row_idx: 0 }),
-                            }),
-                            series.len(),
-                        );
-                        visitor.visit_seq(c)
+                        });
+                        visitor.visit_seq(ChunkedArrayDeserializer::new(iter, series.len()))
                     }
                     DataType::Null => visitor.visit_seq(ChunkedArrayDeserializer::new(
-                        (0..self.series.len())
-                            .into_iter()
-                            .map(|_| Option::<()>::None),
-                        self.series.len(),
+                        (0..series.len()).into_iter().map(|_| Option::<()>::None),
+                        series.len(),
                     )),
                     // categoricals
                     DataType::Categorical(_, _) | DataType::Enum(_, _) => {
                         visitor.visit_seq(ChunkedArrayDeserializer::new(
-                            series.categorical().unwrap().iter_str(),
-                            self.series.len(),
+                            series.categorical()?.iter_str(),
+                            series.len(),
                         ))
                     }
                     DataType::Struct(_) => {
-                        let c = self.series.struct_().unwrap();
-                        let stack = Rc::new(c.fields().to_owned().into_boxed_slice());
+                        let c = series.struct_()?;
+                        let stack = ColumnStack::Shared(Rc::from(visible_fields(c.fields())));
                         let iter = (0..c.len()).into_iter().map(|row_idx| {
-                            Some(PlRowImplMapAccess {
-                                stack: stack.clone(),
-                                map_value_idx: 0,
-                                row_idx,
-                            })
+                            if is_null_at(&series, row_idx) {
+                                return None;
+                            }
+                            Some(PlRowImplMapAccess::from_series_vec(stack.clone(), row_idx))
                         });
 
-                        let deser = ChunkedArrayDeserializer::new(iter, self.series.len());
+                        let deser = ChunkedArrayDeserializer::new(iter, c.len());
                         visitor.visit_seq(deser)
                     }
-                    // todo. Fix this
-                    DataType::Object(_, _) => todo!(),
-                    DataType::Unknown(_) => todo!(),
+                    dtype @ (DataType::Object(_, _) | DataType::Unknown(_)) => {
+                        Err(PlRowSerdeError::unsupported(dtype))
+                    }
                 }
             }
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+            Err(_) => Err(self.mismatch("seq")),
         }
     }
 
@@ -522,10 +819,31 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        match len {
-            0 => visitor.visit_unit(),
-            1 => todo!(),
-            _ => todo!(),
+        match (len, self.series.dtype()) {
+            // `[T; 0]`, whatever the column holds
+            (0, _) => visitor.visit_seq(SeqDeserializer::<_, PlRowSerdeError>::new(
+                std::iter::empty::<()>(),
+            )),
+            // the fields of a struct are the elements, by position
+            (_, DataType::Struct(_)) => {
+                let seq = PlRowImplSeqAccess::new(self.struct_fields()?, len, self.row_idx)
+                    .with_projections(self.projections.clone());
+                visitor.visit_seq(seq)
+            }
+            (_, DataType::Array(_, _)) => match self.array_elements()? {
+                Some(elements) => Self::visit_elements(Some(elements), visitor),
+                None => Err(self.missing("tuple")),
+            },
+            // `[u8; N]` from a Binary cell of exactly `N` bytes
+            (_, DataType::Binary | DataType::BinaryOffset) => {
+                match binary_at(&self.series, self.row_idx)?.map(<[u8]>::len) {
+                    Some(found) if found != len => {
+                        Err(PlRowSerdeError::length_mismatch(len, found))
+                    }
+                    _ => self.deserialize_seq(visitor),
+                }
+            }
+            _ => self.deserialize_seq(visitor),
         }
     }
 
@@ -545,15 +863,16 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        match self.series.struct_() {
-            Ok(s) => {
-                let map = PlRowImplMapAccess::from_series_vec(Rc::new(
-                    s.fields().to_vec().into_boxed_slice(),
-                ));
-                visitor.visit_map(map)
-            }
-            Err(e) => Err(PlRowSerdeError::custom(e.to_string())),
+        // map fields are written as lists of `{key, value}` structs
+        if let DataType::List(_) = self.series.dtype() {
+            return match self.series.list()?.get_as_series(self.row_idx) {
+                Some(entries) => Self::visit_entries(entries, visitor),
+                None => Err(self.missing("map")),
+            };
         }
+        let map = PlRowImplMapAccess::from_series_vec(self.struct_fields()?, self.row_idx)
+            .with_projections(self.projections.clone());
+        visitor.visit_map(map)
     }
 
     fn deserialize_struct<V>(
@@ -565,38 +884,59 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        match self.series.struct_() {
-            Ok(s) => {
-                if let Some(i) = s.iter().skip(self.row_idx).next() {
-                } else {
-                };
-                let map = PlRowImplMapAccess::from_series_vec(Rc::new(
-                    s.fields().to_vec().into_boxed_slice(),
+        // `std::time::Duration` is a `{secs, nanos}` struct to serde
+        if let DataType::Duration(_) = self.series.dtype() {
+            let (ticks, unit) = self.duration_ticks()?;
+            let per_sec = 1_000_000_000 / nanos_per_tick(unit);
+            if ticks < 0 {
+                return Err(PlRowSerdeError::invalid_value(
+                    Unexpected::Signed(ticks),
+                    &"a positive duration",
                 ));
-                visitor.visit_map(map)
             }
-            Err(e) => Err(PlRowSerdeError::custom(e.to_string())),
+            let secs = (ticks / per_sec) as u64;
+            let nanos = (ticks % per_sec * nanos_per_tick(unit)) as u64;
+            let mut map = MapDeserializer::<_, PlRowSerdeError>::new(
+                [("secs", secs), ("nanos", nanos)].into_iter(),
+            );
+            let value = visitor.visit_map(&mut map)?;
+            map.end()?;
+            return Ok(value);
         }
+        let stack = self.struct_fields()?;
+        let projection = Projection::cached(self.projections.as_deref(), fields, &stack);
+        let map = PlRowImplMapAccess::from_series_vec(stack, self.row_idx)
+            .with_projection(projection)
+            .with_projections(self.projections);
+        visitor.visit_map(map)
     }
 
+    /// Unit variants are read from String, Categorical and Enum columns, other
+    /// variants from Struct columns, see [`SeriesDeserItem::struct_variant`].
     fn deserialize_enum<V>(
         self,
-        name: &'static str,
+        _name: &'static str,
         variants: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        match self.series.categorical() {
-            Ok(cat) => {
-                let opts = cat.iter_str().skip(self.row_idx).next();
-                match opts {
-                    None | Some(None) => visitor.visit_none(),
-                    Some(Some(opt)) => visitor.visit_enum(StrDeserializer::new(opt)),
+        match self.series.dtype() {
+            DataType::String => match self.series.str()?.get(self.row_idx) {
+                Some(variant) => {
+                    visitor.visit_enum(PlRowEnumAccess::unit(variant.to_string(), self.row_idx))
                 }
-            }
-            Err(e) => Err(PlRowSerdeError::custom(e)),
+                None => Err(self.missing("enum")),
+            },
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => match self.category()? {
+                Some(variant) => {
+                    visitor.visit_enum(PlRowEnumAccess::unit(variant.to_string(), self.row_idx))
+                }
+                None => Err(self.missing("enum")),
+            },
+            DataType::Struct(_) => visitor.visit_enum(self.struct_variant(variants)?),
+            _ => Err(self.mismatch("enum")),
         }
     }
 
@@ -604,7 +944,15 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_newtype_struct(self)
+        match self.series.dtype() {
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => match self.category()? {
+                Some(identifier) => visitor.visit_str(identifier),
+                None => Err(self.missing("identifier")),
+            },
+            DataType::String => self.deserialize_str(visitor),
+            _ if self.cell_is_null() => Err(self.missing("identifier")),
+            _ => self.deserialize_any(visitor),
+        }
     }
 
     fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -614,3 +962,87 @@ impl<'de> Deserializer<'de> for SeriesDeserItem {
         visitor.visit_unit()
     }
 }
+
+/// An `i128` or `u128` cell, see [`SeriesDeserItem::int128_cell`].
+enum Int128Cell<'a> {
+    Unscaled(i128),
+    Digits(&'a str),
+    Bytes([u8; 16]),
+}
+
+/// The value at `row_idx` of a Binary or BinaryOffset column.
+fn binary_at(series: &Series, row_idx: usize) -> Result<Option<&[u8]>, PlRowSerdeError> {
+    Ok(match series.dtype() {
+        DataType::BinaryOffset => series.binary_offset()?.get(row_idx),
+        _ => series.binary()?.get(row_idx),
+    })
+}
+
+fn utf8(v: &[u8]) -> Result<&str, PlRowSerdeError> {
+    std::str::from_utf8(v)
+        .map_err(|_| PlRowSerdeError::invalid_value(Unexpected::Bytes(v), &"UTF-8 bytes"))
+}
+
+/// Error for a cell of `series` that has no value: either the row is out of
+/// range or the cell is null.
+fn missing_value(series: &Series, row_idx: usize, expected: &'static str) -> PlRowSerdeError {
+    if row_idx >= series.len() {
+        PlRowSerdeError::row_out_of_range(row_idx, series.len())
+    } else {
+        PlRowSerdeError::unexpected_null(expected, series.dtype())
+    }
+}
+
+/// Whether the value at `row_idx` is null.
+///
+/// A struct row is null when all of its fields are, or where its [`VALIDITY`]
+/// field is false when it has one.
+pub(crate) fn is_null_at(series: &Series, row_idx: usize) -> bool {
+    match series.dtype() {
+        DataType::Struct(_) => match series.struct_().map(|s| s.fields()) {
+            Ok([.., valid]) if valid.name() == VALIDITY => {
+                !matches!(valid.bool().map(|i| i.get(row_idx)), Ok(Some(true)))
+            }
+            Ok(fields) => fields.iter().all(|i| is_null_at(i, row_idx)),
+            Err(_) => false,
+        },
+        _ if series.null_count() == 0 => false,
+        _ => matches!(series.get(row_idx), Ok(AnyValue::Null)),
+    }
+}
+
+/// The fields of a struct column without its [`VALIDITY`] field.
+fn visible_fields(fields: &[Series]) -> &[Series] {
+    match fields {
+        [visible @ .., valid] if valid.name() == VALIDITY => visible,
+        _ => fields,
+    }
+}
+
+/// ISO 8601 representation of the physical value of a temporal dtype, `None`
+/// when the value is out of the range chrono can represent.
+fn temporal_to_string(dtype: &DataType, v: i64) -> Option<String> {
+    const ISO_DATETIME: &str = "%Y-%m-%dT%H:%M:%S%.f";
+    match dtype {
+        DataType::Date => date32_to_date_opt(v as i32).map(|i| i.to_string()),
+        DataType::Time => time64ns_to_time_opt(v).map(|i| i.to_string()),
+        DataType::Datetime(unit, tz) => {
+            let dt = match unit {
+                TimeUnit::Nanoseconds => timestamp_ns_to_datetime_opt(v),
+                TimeUnit::Microseconds => timestamp_us_to_datetime_opt(v),
+                TimeUnit::Milliseconds => timestamp_ms_to_datetime_opt(v),
+            }?;
+            // the physical value of a zoned datetime is always UTC, shown at
+            // the offset of the time zone when it is a fixed one
+            Some(match tz.as_deref().map(FixedOffset::from_str) {
+                Some(Ok(offset)) => offset
+                    .from_utc_datetime(&dt)
+                    .format("%Y-%m-%dT%H:%M:%S%.f%:z")
+                    .to_string(),
+                Some(Err(_)) => format!("{}Z", dt.format(ISO_DATETIME)),
+                None => dt.format(ISO_DATETIME).to_string(),
+            })
+        }
+        _ => Some(v.to_string()),
+    }
+}
@@ -41,7 +41,8 @@ where
         V: serde::de::DeserializeSeed<'de>,
     {
         match self.chunked.next() {
-            Some(None) => seed.deserialize(UnitDeserializer::new()).map(|_| None),
+            // a null element, which only an `Option` accepts
+            Some(None) => seed.deserialize(UnitDeserializer::new()).map(Some),
             Some(Some(value)) => seed.deserialize(value.into_deserializer()).map(|i| Some(i)),
             None => Ok(None),
         }
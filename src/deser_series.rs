@@ -1,244 +1,212 @@
+use std::rc::Rc;
+
 use polars::frame::DataFrame;
 use serde::Deserializer;
 
-use crate::{deser_map::PlRowImplMapAccess, pl_row_error};
+use crate::{
+    deser_map::{ColumnStack, PlRowImplMapAccess, PlRowImplSeqAccess, Projection, Projections},
+    pl_row_error::PlRowSerdeError,
+};
 
-pub struct SeriesDeser {
-    pub(crate) df: DataFrame,
+pub struct SeriesDeser<'de> {
+    pub(crate) df: &'de DataFrame,
     pub(crate) row_idx: usize,
+    pub(crate) projections: Option<Rc<Projections>>,
 }
-impl SeriesDeser {
-    pub fn new(df: DataFrame, row_idx: usize) -> Self {
-        Self { df, row_idx }
+impl<'de> SeriesDeser<'de> {
+    pub fn new(df: &'de DataFrame, row_idx: usize) -> Self {
+        Self {
+            df,
+            row_idx,
+            projections: None,
+        }
+    }
+
+    /// Share the projections of struct cells with the other rows read with
+    /// `projections`.
+    pub(crate) fn with_projections(mut self, projections: Rc<Projections>) -> Self {
+        self.projections = Some(projections);
+        self
+    }
+
+    fn check_row(&self) -> Result<(), PlRowSerdeError> {
+        if self.row_idx >= self.df.height() {
+            return Err(PlRowSerdeError::row_out_of_range(
+                self.row_idx,
+                self.df.height(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Visit the row as a map, tagging errors with the row.
+    fn visit_row<V>(
+        &self,
+        map: PlRowImplMapAccess<'de>,
+        visitor: V,
+    ) -> Result<V::Value, PlRowSerdeError>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.check_row()?;
+        visitor.visit_map(map).map_err(|e| e.at_row(self.row_idx))
     }
 }
 
-impl<'de> Deserializer<'de> for SeriesDeser {
-    type Error = pl_row_error::PlRowSerdeError;
+/// A row is a map of columns, so it can't be read as any of these.
+macro_rules! unsupported_row {
+    ($($func: ident, $found: literal;)*) => {
+        $(
+            fn $func<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: serde::de::Visitor<'de>,
+            {
+                Err(PlRowSerdeError::unsupported_type($found))
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for SeriesDeser<'de> {
+    type Error = PlRowSerdeError;
 
     fn deserialize_struct<V>(
         self,
         _: &'static str,
-        _: &'static [&'static str],
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        visitor.visit_map(PlRowImplMapAccess::new(&self))
+        let projection = Projection::cached(
+            self.projections.as_deref(),
+            fields,
+            &ColumnStack::Borrowed(self.df.get_columns()),
+        );
+        self.visit_row(
+            PlRowImplMapAccess::new(&self).with_projection(projection),
+            visitor,
+        )
     }
 
+    /// A row is self-describing as a map of column name to cell.
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
-    }
-
-    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+        self.visit_row(PlRowImplMapAccess::new(&self), visitor)
     }
 
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_some(self)
     }
 
     fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_unit()
     }
 
     fn deserialize_unit_struct<V>(
         self,
-        name: &'static str,
+        _name: &'static str,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_unit()
     }
 
     fn deserialize_newtype_struct<V>(
         self,
-        name: &'static str,
+        _name: &'static str,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
-    }
-
-    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
-    }
-
-    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
-    where
-        V: serde::de::Visitor<'de>,
-    {
-        todo!()
+        visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_tuple_struct<V>(
         self,
-        name: &'static str,
+        _name: &'static str,
         len: usize,
         visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        self.deserialize_tuple(len, visitor)
     }
 
     fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        self.visit_row(PlRowImplMapAccess::new(&self), visitor)
     }
 
     fn deserialize_enum<V>(
         self,
-        name: &'static str,
-        variants: &'static [&'static str],
-        visitor: V,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V,
     ) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("enum"))
     }
 
-    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        visitor.visit_unit()
     }
 
-    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    unsupported_row! {
+        deserialize_bool, "bool";
+        deserialize_i8, "i8";
+        deserialize_i16, "i16";
+        deserialize_i32, "i32";
+        deserialize_i64, "i64";
+        deserialize_u8, "u8";
+        deserialize_u16, "u16";
+        deserialize_u32, "u32";
+        deserialize_u64, "u64";
+        deserialize_i128, "i128";
+        deserialize_u128, "u128";
+        deserialize_f32, "f32";
+        deserialize_f64, "f64";
+        deserialize_char, "char";
+        deserialize_str, "str";
+        deserialize_string, "string";
+        deserialize_bytes, "bytes";
+        deserialize_byte_buf, "bytes";
+        deserialize_seq, "seq";
+        deserialize_identifier, "identifier";
+    }
+
+    /// Tuple rows read the columns by position, whatever their names.
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: serde::de::Visitor<'de>,
     {
-        todo!()
+        self.check_row()?;
+        let seq = PlRowImplSeqAccess::new(
+            ColumnStack::Borrowed(self.df.get_columns()),
+            len,
+            self.row_idx,
+        )
+        .with_projections(self.projections.clone());
+        visitor.visit_seq(seq).map_err(|e| e.at_row(self.row_idx))
     }
 }
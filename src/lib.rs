@@ -1,11 +1,15 @@
 #![doc = include_str!("./lib.doc.md")]
+pub use deser_cursor::{DataFrameRowIter, RowCursor};
 use deser_series::SeriesDeser;
-use pl_row_error::PlRowSerdeError;
+pub use pl_row_error::PlRowSerdeError;
 use polars::frame::DataFrame;
+pub use ser_options::{DtypeConflict, EnumLayout, Int128Layout, SerializeOptions};
 use ser_root::PlRowSerStruct;
-use serde::{de::Error, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize};
 
 //deserialize
+pub(crate) mod deser_cursor;
+pub(crate) mod deser_enum;
 pub(crate) mod deser_map;
 pub(crate) mod deser_root;
 pub(crate) mod deser_seq;
@@ -15,32 +19,66 @@ pub(crate) mod deser_series;
 pub(crate) mod pl_row_error;
 
 // serialize
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+pub(crate) mod ser_builder;
+pub(crate) mod ser_options;
 pub(crate) mod ser_root;
-//pub mod ser_seq;
+#[cfg(feature = "chrono")]
+pub mod temporal;
+#[cfg(any(feature = "chrono", feature = "rust_decimal"))]
+pub(crate) mod wrapped;
 //pub mod series_serde_root;
 
 /// Deserialize a row from given dataframe.
 ///
-pub fn deserialize_single_row<'de, T>(df: DataFrame, row_idx: usize) -> Result<T, PlRowSerdeError>
+pub fn deserialize_single_row<T>(df: DataFrame, row_idx: usize) -> Result<T, PlRowSerdeError>
+where
+    T: DeserializeOwned,
+{
+    deserialize_single_row_borrowed(&df, row_idx)
+}
+
+/// Deserialize a row from given dataframe, borrowing from it.
+///
+/// `&'de str`, `&'de [u8]` and `#[serde(borrow)] Cow<'de, str>` fields point
+/// straight into the String/Binary columns instead of being copied.
+pub fn deserialize_single_row_borrowed<'de, T>(
+    df: &'de DataFrame,
+    row_idx: usize,
+) -> Result<T, PlRowSerdeError>
 where
     T: Deserialize<'de>,
 {
-    <T as Deserialize>::deserialize(SeriesDeser { df, row_idx })
+    <T as Deserialize>::deserialize(SeriesDeser::new(df, row_idx))
 }
 
 /// Deserialize whole dataframe.
-pub fn deserialize_all<'de, T>(df: DataFrame) -> Vec<Result<T, PlRowSerdeError>>
+pub fn deserialize_all<T>(df: DataFrame) -> Vec<Result<T, PlRowSerdeError>>
+where
+    T: DeserializeOwned,
+{
+    deserialize_all_borrowed(&df)
+}
+
+/// Deserialize whole dataframe, borrowing from it.
+///
+/// See [`deserialize_single_row_borrowed`].
+pub fn deserialize_all_borrowed<'de, T>(df: &'de DataFrame) -> Vec<Result<T, PlRowSerdeError>>
 where
     T: Deserialize<'de>,
 {
-    let mut stack = Vec::with_capacity(df.height());
-    for row_idx in 0..df.height() {
-        stack.push(<T as Deserialize>::deserialize(SeriesDeser {
-            df: df.clone(),
-            row_idx,
-        }));
-    }
-    stack
+    iter_rows(df).collect()
+}
+
+/// Lazily deserialize every row of the dataframe.
+///
+/// Columns are resolved once rather than per row, see [`RowCursor`].
+pub fn iter_rows<'de, T>(df: &'de DataFrame) -> DataFrameRowIter<'de, T>
+where
+    T: Deserialize<'de>,
+{
+    DataFrameRowIter::new(df)
 }
 
 /// Serialize rust iterator into a dataframe.
@@ -49,18 +87,24 @@ where
     I: Iterator<Item = T>,
     T: serde::Serialize,
 {
-    let mut plr = PlRowSerStruct::default();
+    serialize_into_dataframe_with_options(iter, SerializeOptions::default())
+}
+
+/// Serialize rust iterator into a dataframe, see [`SerializeOptions`].
+pub fn serialize_into_dataframe_with_options<T, I>(
+    iter: I,
+    options: SerializeOptions,
+) -> Result<DataFrame, PlRowSerdeError>
+where
+    I: Iterator<Item = T>,
+    T: serde::Serialize,
+{
+    let mut plr = PlRowSerStruct::with_options(options, iter.size_hint().0);
 
     for i in iter {
-        let res = i.serialize(plr);
-        match res {
-            Ok(a) => {
-                plr = a;
-            }
-            Err(e) => return Err(PlRowSerdeError::custom(e)),
-        }
+        i.serialize(&mut plr)?;
     }
-    Ok(plr.into_dataframe())
+    plr.into_dataframe()
 }
 
 /// Serialize a rust type into a dataframe.
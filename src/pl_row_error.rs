@@ -1,30 +1,259 @@
-pub type PlRowSerdeError = serde::de::value::Error;
-#[derive(Debug)]
-pub enum PlRowSerdeErrorTypes {
-    TypeMisMatch,
-    ObjectNotSupported,
-    UnknownNotSupported,
-    Custom(String),
+use std::fmt::{self, Display};
+
+use polars::{datatypes::DataType, error::PolarsError};
+
+/// Error returned while converting between rows and dataframes.
+///
+/// `column` is the path of the column the error happened in (`outer.inner` for
+/// fields of struct columns) and `row` the row of the dataframe. Both are
+/// `None` when the error isn't tied to a cell, e.g. when a whole row is out of
+/// range or a serializer fails before any column is written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlRowSerdeError {
+    /// The column can not be read as the rust type, or a value can not be
+    /// written into a column of another dtype.
+    TypeMismatch {
+        column: Option<String>,
+        row: Option<usize>,
+        expected: &'static str,
+        found: DataType,
+    },
+    /// A field without `Option` or `#[serde(default)]` has no column.
+    MissingColumn { column: String, row: Option<usize> },
+    /// The cell is null but the field is not an `Option`.
+    UnexpectedNull {
+        column: Option<String>,
+        row: Option<usize>,
+        expected: &'static str,
+        found: DataType,
+    },
+    /// The row is past the end of the dataframe.
+    RowOutOfRange {
+        column: Option<String>,
+        row: usize,
+        height: usize,
+    },
+    /// The dtype can not be converted at all.
+    UnsupportedDataType {
+        column: Option<String>,
+        row: Option<usize>,
+        found: DataType,
+    },
+    /// A seq written to an Array column has another length than the rows
+    /// before it.
+    LengthMismatch {
+        column: Option<String>,
+        row: Option<usize>,
+        expected: usize,
+        found: usize,
+    },
+    /// The serde type (`"map"`, `"tuple"`, ...) can not be converted here.
+    UnsupportedType {
+        column: Option<String>,
+        row: Option<usize>,
+        found: &'static str,
+    },
+    /// Anything else, like errors raised by a `Deserialize` impl or by polars.
+    Custom {
+        column: Option<String>,
+        row: Option<usize>,
+        message: String,
+    },
 }
-impl std::fmt::Display for PlRowSerdeErrorTypes {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("ok")
+
+impl PlRowSerdeError {
+    pub(crate) fn type_mismatch(expected: &'static str, found: &DataType) -> Self {
+        Self::TypeMismatch {
+            column: None,
+            row: None,
+            expected,
+            found: found.clone(),
+        }
+    }
+
+    pub(crate) fn unexpected_null(expected: &'static str, found: &DataType) -> Self {
+        Self::UnexpectedNull {
+            column: None,
+            row: None,
+            expected,
+            found: found.clone(),
+        }
+    }
+
+    pub(crate) fn row_out_of_range(row: usize, height: usize) -> Self {
+        Self::RowOutOfRange {
+            column: None,
+            row,
+            height,
+        }
+    }
+
+    pub(crate) fn unsupported(found: &DataType) -> Self {
+        Self::UnsupportedDataType {
+            column: None,
+            row: None,
+            found: found.clone(),
+        }
+    }
+
+    pub(crate) fn length_mismatch(expected: usize, found: usize) -> Self {
+        Self::LengthMismatch {
+            column: None,
+            row: None,
+            expected,
+            found,
+        }
+    }
+
+    pub(crate) fn unsupported_type(found: &'static str) -> Self {
+        Self::UnsupportedType {
+            column: None,
+            row: None,
+            found,
+        }
+    }
+
+    /// Column the error happened in, see [`PlRowSerdeError`].
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            Self::MissingColumn { column, .. } => Some(column),
+            Self::TypeMismatch { column, .. }
+            | Self::UnexpectedNull { column, .. }
+            | Self::RowOutOfRange { column, .. }
+            | Self::UnsupportedDataType { column, .. }
+            | Self::LengthMismatch { column, .. }
+            | Self::UnsupportedType { column, .. }
+            | Self::Custom { column, .. } => column.as_deref(),
+        }
+    }
+
+    /// Row the error happened in.
+    pub fn row(&self) -> Option<usize> {
+        match self {
+            Self::RowOutOfRange { row, .. } => Some(*row),
+            Self::TypeMismatch { row, .. }
+            | Self::MissingColumn { row, .. }
+            | Self::UnexpectedNull { row, .. }
+            | Self::UnsupportedDataType { row, .. }
+            | Self::LengthMismatch { row, .. }
+            | Self::UnsupportedType { row, .. }
+            | Self::Custom { row, .. } => *row,
+        }
+    }
+
+    /// Attach the column `name` read at `row_idx`.
+    ///
+    /// Called on the way out of every map access, so the column becomes a path
+    /// of the struct fields it was read through and the row ends up being the
+    /// row of the dataframe.
+    pub(crate) fn within(mut self, name: &str, row_idx: usize) -> Self {
+        let (column, row) = match &mut self {
+            Self::MissingColumn { column, row } => {
+                *column = format!("{name}.{column}");
+                *row = Some(row_idx);
+                return self;
+            }
+            Self::RowOutOfRange { column, .. } => (column, None),
+            Self::TypeMismatch { column, row, .. }
+            | Self::UnexpectedNull { column, row, .. }
+            | Self::UnsupportedDataType { column, row, .. }
+            | Self::LengthMismatch { column, row, .. }
+            | Self::UnsupportedType { column, row, .. }
+            | Self::Custom { column, row, .. } => (column, Some(row)),
+        };
+        *column = Some(match column.take() {
+            Some(inner) => format!("{name}.{inner}"),
+            None => name.to_string(),
+        });
+        if let Some(row) = row {
+            *row = Some(row_idx);
+        }
+        self
+    }
+
+    /// Attach the row of the dataframe to an error that isn't tied to a column.
+    pub(crate) fn at_row(mut self, row_idx: usize) -> Self {
+        match &mut self {
+            Self::RowOutOfRange { .. } => (),
+            Self::TypeMismatch { row, .. }
+            | Self::MissingColumn { row, .. }
+            | Self::UnexpectedNull { row, .. }
+            | Self::UnsupportedDataType { row, .. }
+            | Self::LengthMismatch { row, .. }
+            | Self::UnsupportedType { row, .. }
+            | Self::Custom { row, .. } => {
+                row.get_or_insert(row_idx);
+            }
+        }
+        self
     }
 }
-impl serde::ser::StdError for PlRowSerdeErrorTypes {}
-impl serde::de::Error for PlRowSerdeErrorTypes {
+
+impl Display for PlRowSerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.column(), self.row()) {
+            (Some(column), Some(row)) => write!(f, "column `{column}`, row {row}: ")?,
+            (Some(column), None) => write!(f, "column `{column}`: ")?,
+            (None, Some(row)) => write!(f, "row {row}: ")?,
+            (None, None) => (),
+        }
+        match self {
+            Self::TypeMismatch {
+                expected, found, ..
+            } => write!(f, "expected {expected}, found {found}"),
+            Self::MissingColumn { .. } => f.write_str("missing column"),
+            Self::UnexpectedNull {
+                expected, found, ..
+            } => write!(f, "expected {expected}, found null in a {found} column"),
+            Self::RowOutOfRange { height, .. } => {
+                write!(f, "out of range for a dataframe of height {height}")
+            }
+            Self::UnsupportedDataType { found, .. } => write!(f, "{found} is not supported"),
+            Self::LengthMismatch {
+                expected, found, ..
+            } => write!(
+                f,
+                "expected {expected} elements like the rows before, found {found}"
+            ),
+            Self::UnsupportedType { found, .. } => write!(f, "{found} is not supported"),
+            Self::Custom { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for PlRowSerdeError {}
+
+impl serde::de::Error for PlRowSerdeError {
     fn custom<T>(msg: T) -> Self
     where
-        T: std::fmt::Display,
+        T: Display,
     {
-        Self::Custom(msg.to_string())
+        Self::Custom {
+            column: None,
+            row: None,
+            message: msg.to_string(),
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::MissingColumn {
+            column: field.to_string(),
+            row: None,
+        }
+    }
+}
+
+impl serde::ser::Error for PlRowSerdeError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        <Self as serde::de::Error>::custom(msg)
     }
 }
 
-#[macro_export]
-macro_rules! custom_error {
-    ($s: literal) => {
-        let scl = source_code_location::new_string!();
-        serde::de::value::Error::custom(format!("error at {scl}. Detail: {$s}"))
-    };
+impl From<PolarsError> for PlRowSerdeError {
+    fn from(e: PolarsError) -> Self {
+        <Self as serde::de::Error>::custom(e)
+    }
 }
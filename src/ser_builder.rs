@@ -0,0 +1,649 @@
+use std::collections::HashMap;
+
+use polars::{
+    datatypes::{
+        DataType, Field, Float32Type, Float64Type, Int128Chunked, Int16Type, Int32Type, Int64Type,
+        Int8Type, TimeUnit, TimeZone, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    export::arrow::{array::ListArray, bitmap::Bitmap, offset::Offsets},
+    prelude::{
+        try_get_supertype, BinaryChunkedBuilder, BooleanChunkedBuilder, ChunkedBuilder, IdxCa,
+        IdxSize, IntoSeries, ListChunked, NamedFrom, NewChunkedArray, PrimitiveChunkedBuilder,
+        StringChunkedBuilder, StructChunked,
+    },
+    series::Series,
+};
+
+use crate::{deser_root::is_null_at, pl_row_error::PlRowSerdeError, ser_options::DtypeConflict};
+
+/// Values of a column, typed after the first non-null value written to it.
+pub(crate) enum ColumnBuilder {
+    /// Only nulls so far, this many.
+    Null(usize),
+    Boolean(BooleanChunkedBuilder),
+    Int8(PrimitiveChunkedBuilder<Int8Type>),
+    Int16(PrimitiveChunkedBuilder<Int16Type>),
+    Int32(PrimitiveChunkedBuilder<Int32Type>),
+    Int64(PrimitiveChunkedBuilder<Int64Type>),
+    UInt8(PrimitiveChunkedBuilder<UInt8Type>),
+    UInt16(PrimitiveChunkedBuilder<UInt16Type>),
+    UInt32(PrimitiveChunkedBuilder<UInt32Type>),
+    UInt64(PrimitiveChunkedBuilder<UInt64Type>),
+    Float32(PrimitiveChunkedBuilder<Float32Type>),
+    Float64(PrimitiveChunkedBuilder<Float64Type>),
+    Binary(BinaryChunkedBuilder),
+    /// Days since the epoch.
+    Date(PrimitiveChunkedBuilder<Int32Type>),
+    /// Ticks of the time unit since the epoch, in UTC when zoned.
+    Datetime(
+        PrimitiveChunkedBuilder<Int64Type>,
+        TimeUnit,
+        Option<TimeZone>,
+    ),
+    /// Nanoseconds since midnight.
+    Time(PrimitiveChunkedBuilder<Int64Type>),
+    /// Ticks of the time unit.
+    Duration(PrimitiveChunkedBuilder<Int64Type>, TimeUnit),
+    Decimal(DecimalColumn),
+    String(StringChunkedBuilder),
+    /// Unit variants of an enum, Categorical once finished.
+    Categorical(StringChunkedBuilder),
+    List(Box<ListColumn>),
+    Struct(StructColumn),
+}
+
+impl ColumnBuilder {
+    /// Dtype of the finished column, for errors.
+    pub fn dtype(&self) -> DataType {
+        match self {
+            Self::Null(_) => DataType::Null,
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Int8(_) => DataType::Int8,
+            Self::Int16(_) => DataType::Int16,
+            Self::Int32(_) => DataType::Int32,
+            Self::Int64(_) => DataType::Int64,
+            Self::UInt8(_) => DataType::UInt8,
+            Self::UInt16(_) => DataType::UInt16,
+            Self::UInt32(_) => DataType::UInt32,
+            Self::UInt64(_) => DataType::UInt64,
+            Self::Float32(_) => DataType::Float32,
+            Self::Float64(_) => DataType::Float64,
+            Self::Binary(_) => DataType::Binary,
+            Self::Date(_) => DataType::Date,
+            Self::Datetime(_, unit, tz) => DataType::Datetime(*unit, tz.clone()),
+            Self::Time(_) => DataType::Time,
+            Self::Duration(_, unit) => DataType::Duration(*unit),
+            Self::Decimal(decimal) => {
+                DataType::Decimal(Some(DECIMAL_PRECISION), Some(decimal.scale))
+            }
+            Self::String(_) => DataType::String,
+            Self::Categorical(_) => DataType::Categorical(None, Default::default()),
+            Self::List(list) => match (list.array, list.width) {
+                (true, Some(width)) => DataType::Array(Box::new(list.inner.builder.dtype()), width),
+                _ => DataType::List(Box::new(list.inner.builder.dtype())),
+            },
+            Self::Struct(fields) => DataType::Struct(
+                fields
+                    .columns
+                    .iter()
+                    .map(|i| Field::new(&i.name, i.builder.dtype()))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn append_null(&mut self) {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(b) => b.append_null(),
+            Self::Int8(b) => b.append_null(),
+            Self::Int16(b) => b.append_null(),
+            Self::Int32(b) => b.append_null(),
+            Self::Int64(b) => b.append_null(),
+            Self::UInt8(b) => b.append_null(),
+            Self::UInt16(b) => b.append_null(),
+            Self::UInt32(b) => b.append_null(),
+            Self::UInt64(b) => b.append_null(),
+            Self::Float32(b) => b.append_null(),
+            Self::Float64(b) => b.append_null(),
+            Self::Binary(b) => b.append_null(),
+            Self::Date(b) => b.append_null(),
+            Self::Datetime(b, _, _) | Self::Time(b) | Self::Duration(b, _) => b.append_null(),
+            Self::Decimal(decimal) => decimal.values.push(None),
+            Self::String(b) | Self::Categorical(b) => b.append_null(),
+            Self::List(list) => list.append_null(),
+            // a null struct row has all of its fields null
+            Self::Struct(fields) => {
+                fields.columns.iter_mut().for_each(Column::append_null);
+                fields.validity.push(false);
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Series, PlRowSerdeError> {
+        Ok(match self {
+            Self::Null(len) => Series::new_null("", len),
+            Self::Boolean(b) => b.finish().into_series(),
+            Self::Int8(b) => b.finish().into_series(),
+            Self::Int16(b) => b.finish().into_series(),
+            Self::Int32(b) => b.finish().into_series(),
+            Self::Int64(b) => b.finish().into_series(),
+            Self::UInt8(b) => b.finish().into_series(),
+            Self::UInt16(b) => b.finish().into_series(),
+            Self::UInt32(b) => b.finish().into_series(),
+            Self::UInt64(b) => b.finish().into_series(),
+            Self::Float32(b) => b.finish().into_series(),
+            Self::Float64(b) => b.finish().into_series(),
+            Self::Binary(b) => b.finish().into_series(),
+            Self::Date(b) => b.finish().into_series().cast(&DataType::Date)?,
+            Self::Datetime(b, unit, tz) => b
+                .finish()
+                .into_series()
+                .cast(&DataType::Datetime(unit, tz))?,
+            Self::Time(b) => b.finish().into_series().cast(&DataType::Time)?,
+            Self::Duration(b, unit) => b.finish().into_series().cast(&DataType::Duration(unit))?,
+            Self::String(b) => b.finish().into_series(),
+            Self::Categorical(b) => b
+                .finish()
+                .into_series()
+                .cast(&DataType::Categorical(None, Default::default()))?,
+            Self::Decimal(decimal) => {
+                Int128Chunked::from_iter_options("", decimal.values.into_iter())
+                    .into_decimal(Some(DECIMAL_PRECISION), decimal.scale)?
+                    .into_series()
+            }
+            Self::List(list) => list.finish()?,
+            Self::Struct(fields) if fields.tuple => fields.finish_tuple()?,
+            Self::Struct(mut fields) => {
+                let validity = std::mem::take(&mut fields.validity);
+                struct_series(fields.finish()?, validity)?
+            }
+        })
+    }
+}
+
+/// A column being built, see [`ColumnBuilder`].
+pub(crate) struct Column {
+    pub name: String,
+    /// Values written so far, nulls included.
+    pub len: usize,
+    /// Rows expected, to size the builder once the column is typed.
+    pub capacity: usize,
+    pub builder: ColumnBuilder,
+    /// Values written before the builder changed type, see [`DtypeConflict`].
+    chunks: Vec<Series>,
+    /// Values in `chunks`.
+    done: usize,
+    on_conflict: DtypeConflict,
+}
+
+impl Column {
+    /// A column `name` that starts with `len` nulls, for the rows written
+    /// before it first showed up.
+    pub fn new(name: impl Into<String>, len: usize, capacity: usize) -> Self {
+        Self {
+            name: name.into(),
+            len,
+            capacity,
+            builder: ColumnBuilder::Null(len),
+            chunks: vec![],
+            done: 0,
+            on_conflict: DtypeConflict::Error,
+        }
+    }
+
+    pub fn append_null(&mut self) {
+        self.builder.append_null();
+        self.len += 1;
+    }
+
+    /// The builder picked by `get`, created with `init` and back-filled with
+    /// the nulls written so far if the column isn't typed yet. A column typed
+    /// otherwise is handled after `on_conflict`.
+    pub fn typed<B>(
+        &mut self,
+        on_conflict: DtypeConflict,
+        expected: &'static str,
+        init: impl FnOnce(usize) -> ColumnBuilder,
+        get: impl Fn(&mut ColumnBuilder) -> Option<&mut B>,
+    ) -> Result<&mut B, PlRowSerdeError> {
+        if let ColumnBuilder::Null(nulls) = self.builder {
+            let mut builder = init(self.capacity.max(nulls + 1));
+            for _ in 0..nulls {
+                builder.append_null();
+            }
+            self.builder = builder;
+        } else if get(&mut self.builder).is_none() {
+            self.split(on_conflict, expected, init)?;
+        }
+        get(&mut self.builder).ok_or_else(|| PlRowSerdeError::unsupported_type(expected))
+    }
+
+    /// Set the values so far aside and go on with a builder from `init`, for a
+    /// value that doesn't fit the column. The values are cast to a common
+    /// dtype on [`Column::finish`].
+    fn split(
+        &mut self,
+        on_conflict: DtypeConflict,
+        expected: &'static str,
+        init: impl FnOnce(usize) -> ColumnBuilder,
+    ) -> Result<(), PlRowSerdeError> {
+        let found = self.builder.dtype();
+        if on_conflict == DtypeConflict::Error {
+            return Err(PlRowSerdeError::type_mismatch(expected, &found));
+        }
+        let builder = init(self.capacity.saturating_sub(self.len).max(1));
+        if on_conflict == DtypeConflict::Supertype
+            && try_get_supertype(&found, &builder.dtype()).is_err()
+        {
+            return Err(PlRowSerdeError::type_mismatch(expected, &found));
+        }
+        let chunk = std::mem::replace(&mut self.builder, builder).finish()?;
+        self.chunks.push(chunk);
+        self.done = self.len;
+        self.on_conflict = on_conflict;
+        Ok(())
+    }
+
+    /// Fields of a struct column, typing the column as a struct if it only
+    /// holds nulls so far.
+    pub fn struct_fields(
+        &mut self,
+        on_conflict: DtypeConflict,
+        expected: &'static str,
+    ) -> Result<&mut StructColumn, PlRowSerdeError> {
+        self.typed(
+            on_conflict,
+            expected,
+            |_| ColumnBuilder::Struct(StructColumn::default()),
+            |b| match b {
+                ColumnBuilder::Struct(fields) => Some(fields),
+                _ => None,
+            },
+        )
+    }
+
+    /// Field `name` of a struct column, see [`Column::struct_fields`] and
+    /// [`StructColumn::field`].
+    pub fn field(
+        &mut self,
+        on_conflict: DtypeConflict,
+        name: &str,
+        expected: &'static str,
+    ) -> Result<&mut Column, PlRowSerdeError> {
+        let (len, capacity) = (self.len - self.done, self.capacity);
+        Ok(self
+            .struct_fields(on_conflict, expected)?
+            .field(name, len, capacity))
+    }
+
+    /// Field `name` of the struct column of an enum, see
+    /// [`StructColumn::variant_field`].
+    pub fn variant_field(
+        &mut self,
+        on_conflict: DtypeConflict,
+        name: &str,
+        expected: &'static str,
+    ) -> Result<&mut Column, PlRowSerdeError> {
+        let (len, capacity) = (self.len - self.done, self.capacity);
+        Ok(self
+            .struct_fields(on_conflict, expected)?
+            .variant_field(name, len, capacity))
+    }
+
+    /// Field at position `idx` of a struct column, named by `name` if it's new.
+    pub fn positional(
+        &mut self,
+        on_conflict: DtypeConflict,
+        idx: usize,
+        expected: &'static str,
+        name: impl FnMut(usize) -> String,
+    ) -> Result<&mut Column, PlRowSerdeError> {
+        let (len, capacity) = (self.len - self.done, self.capacity);
+        Ok(self
+            .struct_fields(on_conflict, expected)?
+            .positional(idx, len, capacity, name))
+    }
+
+    /// Close the row of a struct column, padding the fields it didn't write
+    /// with nulls.
+    pub fn end_struct_row(&mut self) -> Result<(), PlRowSerdeError> {
+        let len = self.len + 1 - self.done;
+        let ColumnBuilder::Struct(fields) = &mut self.builder else {
+            return Err(PlRowSerdeError::type_mismatch(
+                "struct",
+                &self.builder.dtype(),
+            ));
+        };
+        for column in fields.columns.iter_mut() {
+            while column.len < len {
+                column.append_null();
+            }
+        }
+        fields.next = 0;
+        fields.validity.push(true);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Turn a column of unit variants into the struct of an enum with data,
+    /// the variants so far becoming the `tag` field.
+    pub fn unit_variants_into_struct(&mut self, tag: &str) {
+        let builder = std::mem::replace(&mut self.builder, ColumnBuilder::Null(0));
+        self.builder = match builder {
+            ColumnBuilder::Categorical(tags) => {
+                let mut column = Column::new(tag, 0, self.capacity);
+                column.len = self.len - self.done;
+                column.builder = ColumnBuilder::String(tags);
+                let mut fields = StructColumn::default();
+                fields.push(column);
+                ColumnBuilder::Struct(fields)
+            }
+            builder => builder,
+        };
+    }
+
+    pub fn finish(self) -> Result<Series, PlRowSerdeError> {
+        let last = self.builder.finish()?;
+        let series = if self.chunks.is_empty() {
+            last
+        } else {
+            let dtype = match self.on_conflict {
+                DtypeConflict::String => DataType::String,
+                _ => self
+                    .chunks
+                    .iter()
+                    .try_fold(last.dtype().clone(), |acc, i| {
+                        try_get_supertype(&acc, i.dtype())
+                            .map_err(|_| PlRowSerdeError::type_mismatch("supertype", i.dtype()))
+                    })?,
+            };
+            let mut series = Series::new_empty("", &dtype);
+            for chunk in self.chunks.iter().chain(std::iter::once(&last)) {
+                series.append(&chunk.cast(&dtype)?)?;
+            }
+            series
+        };
+        Ok(series.with_name(&self.name))
+    }
+}
+
+/// Digits of the Decimal columns written, the most an `i128` always holds.
+pub(crate) const DECIMAL_PRECISION: usize = 38;
+
+/// Unscaled values of a Decimal column.
+pub(crate) struct DecimalColumn {
+    values: Vec<Option<i128>>,
+    pub scale: usize,
+    /// Whether the scale is set by the options. Otherwise it grows to fit the
+    /// decimals of the values.
+    fixed: bool,
+}
+
+impl DecimalColumn {
+    pub fn new(capacity: usize, scale: Option<usize>) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            scale: scale.unwrap_or(0),
+            fixed: scale.is_some(),
+        }
+    }
+
+    /// Append `unscaled` with `scale` decimals, erroring when it doesn't fit
+    /// the precision or would lose decimals to the scale of the column.
+    pub fn append_value(&mut self, unscaled: i128, scale: usize) -> Result<(), PlRowSerdeError> {
+        let target = if self.fixed {
+            self.scale
+        } else {
+            self.scale.max(scale)
+        };
+        let overflow = || {
+            <PlRowSerdeError as serde::ser::Error>::custom(format!(
+                "decimal {unscaled}e-{scale} doesn't fit Decimal({DECIMAL_PRECISION}, {target})"
+            ))
+        };
+        if target > self.scale {
+            self.values = self
+                .values
+                .iter()
+                .map(|i| i.map(|v| rescale(v, self.scale, target)).transpose())
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(overflow)?;
+            self.scale = target;
+        }
+        let value = rescale(unscaled, scale, target).ok_or_else(overflow)?;
+        self.values.push(Some(value));
+        Ok(())
+    }
+}
+
+/// `value` with `from` decimals as one with `to` decimals, `None` when digits
+/// are lost or it has more than [`DECIMAL_PRECISION`].
+fn rescale(value: i128, from: usize, to: usize) -> Option<i128> {
+    let value = if to >= from {
+        value.checked_mul(10i128.checked_pow((to - from) as u32)?)?
+    } else {
+        let factor = 10i128.checked_pow((from - to) as u32)?;
+        (value % factor == 0).then_some(value / factor)?
+    };
+    (value.unsigned_abs() < 10u128.pow(DECIMAL_PRECISION as u32)).then_some(value)
+}
+
+/// Elements of a list column, flattened, with the offsets of each row.
+pub(crate) struct ListColumn {
+    pub inner: Column,
+    offsets: Vec<i64>,
+    validity: Vec<bool>,
+    /// Whether to finish as an Array column, every row having `width`
+    /// elements.
+    array: bool,
+    width: Option<usize>,
+}
+
+impl ListColumn {
+    pub fn new(capacity: usize, array: bool) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Self {
+            // at least an element per row
+            inner: Column::new("", 0, capacity),
+            offsets,
+            validity: Vec::with_capacity(capacity),
+            array,
+            width: None,
+        }
+    }
+
+    /// Close the row, made of the elements written since the last one.
+    pub fn end_row(&mut self) -> Result<(), PlRowSerdeError> {
+        let end = self.inner.len as i64;
+        if self.array {
+            let len = (end - self.offsets[self.offsets.len() - 1]) as usize;
+            match self.width {
+                Some(width) if width != len => {
+                    return Err(PlRowSerdeError::length_mismatch(width, len))
+                }
+                _ => self.width = Some(len),
+            }
+        }
+        self.offsets.push(end);
+        self.validity.push(true);
+        Ok(())
+    }
+
+    fn append_null(&mut self) {
+        self.offsets.push(self.inner.len as i64);
+        self.validity.push(false);
+    }
+
+    /// One list array over the elements, sliced by the offsets.
+    fn finish(self) -> Result<Series, PlRowSerdeError> {
+        let inner = self.inner.finish()?;
+        let dtype = inner.dtype().clone();
+        let lists = list_series(inner, self.offsets, self.validity)?;
+        // polars has no Arrays of width 0, these stay lists of no elements
+        if let (true, Some(width @ 1..)) = (self.array, self.width) {
+            return Ok(lists.cast(&DataType::Array(Box::new(dtype), width))?);
+        }
+        Ok(lists)
+    }
+}
+
+/// Last field of a Struct column with rows that aren't null but whose fields
+/// all are, false in the null rows.
+pub(crate) const VALIDITY: &str = "$polars_deser_row::validity";
+
+/// A Struct column of `fields`. Polars structs have no validity of their own,
+/// a row is null when all of its fields are, so the rows of null fields that
+/// aren't null are told apart by a [`VALIDITY`] field.
+fn struct_series(mut fields: Vec<Series>, validity: Vec<bool>) -> Result<Series, PlRowSerdeError> {
+    let ambiguous = validity
+        .iter()
+        .enumerate()
+        .any(|(row, valid)| *valid && fields.iter().all(|i| is_null_at(i, row)));
+    if ambiguous {
+        fields.push(Series::new(VALIDITY, validity));
+    } else if fields.is_empty() {
+        // a struct of no fields would have no rows either
+        fields.push(Series::full_null("", validity.len(), &DataType::Null));
+    }
+    Ok(StructChunked::new("", &fields)?.into_series())
+}
+
+/// A List column over `values`, row `i` being `offsets[i]..offsets[i + 1]`.
+fn list_series(
+    values: Series,
+    offsets: Vec<i64>,
+    validity: Vec<bool>,
+) -> Result<Series, PlRowSerdeError> {
+    let values = values.rechunk();
+    let dtype = DataType::List(Box::new(values.dtype().clone()));
+    let values = values.chunks()[0].clone();
+    let validity = validity
+        .contains(&false)
+        .then(|| Bitmap::from_iter(validity));
+    let array = ListArray::<i64>::try_new(
+        ListArray::<i64>::default_datatype(values.data_type().clone()),
+        Offsets::try_from(offsets)?.into(),
+        values,
+        validity,
+    )?;
+    // SAFETY: the values are the physical array of the inner dtype
+    let lists = unsafe { ListChunked::from_chunks_and_dtype("", vec![Box::new(array)], dtype) };
+    Ok(lists.into_series())
+}
+
+/// Fields of a struct column, or the columns of a frame.
+#[derive(Default)]
+pub(crate) struct StructColumn {
+    /// In the order they were first written.
+    pub columns: Vec<Column>,
+    positions: HashMap<String, usize>,
+    /// Field expected next. Rows write their fields in the same order, so this
+    /// spares the lookup by name.
+    next: usize,
+    /// Whether the fields are the elements of a tuple, see
+    /// [`StructColumn::finish_tuple`].
+    pub tuple: bool,
+    /// Rows that aren't null.
+    validity: Vec<bool>,
+}
+
+impl StructColumn {
+    /// Field `name`, added with `len` nulls if it's new. New fields go right
+    /// after the field written before them, so the fields keep the order of
+    /// declaration even if the first rows skip some.
+    pub fn field(&mut self, name: &str, len: usize, capacity: usize) -> &mut Column {
+        self.get_or_insert(name, len, capacity, self.next)
+    }
+
+    /// Like [`StructColumn::field`], but new fields go last. The fields of an
+    /// enum come from all of its variants, so the order they are met in is the
+    /// only one there is.
+    pub fn variant_field(&mut self, name: &str, len: usize, capacity: usize) -> &mut Column {
+        self.get_or_insert(name, len, capacity, self.columns.len())
+    }
+
+    fn get_or_insert(&mut self, name: &str, len: usize, capacity: usize, at: usize) -> &mut Column {
+        let idx = match self.columns.get(self.next) {
+            Some(column) if column.name == name => self.next,
+            _ => match self.positions.get(name) {
+                Some(idx) => *idx,
+                None => self.insert(at, Column::new(name, len, capacity)),
+            },
+        };
+        self.next = idx + 1;
+        &mut self.columns[idx]
+    }
+
+    /// Field at position `idx`, added with `len` nulls and the name given by
+    /// `name` if it's new.
+    pub fn positional(
+        &mut self,
+        idx: usize,
+        len: usize,
+        capacity: usize,
+        mut name: impl FnMut(usize) -> String,
+    ) -> &mut Column {
+        while self.columns.len() <= idx {
+            let name = name(self.columns.len());
+            self.push(Column::new(name, len, capacity));
+        }
+        self.next = idx + 1;
+        &mut self.columns[idx]
+    }
+
+    /// Add `column` as the last field, returning its position.
+    pub fn push(&mut self, column: Column) -> usize {
+        self.insert(self.columns.len(), column)
+    }
+
+    /// Add `column` at position `idx`, moving the fields from there on.
+    fn insert(&mut self, idx: usize, column: Column) -> usize {
+        self.columns.insert(idx, column);
+        for (idx, column) in self.columns.iter().enumerate().skip(idx) {
+            self.positions.insert(column.name.clone(), idx);
+        }
+        idx
+    }
+
+    pub fn finish(self) -> Result<Vec<Series>, PlRowSerdeError> {
+        self.columns.into_iter().map(Column::finish).collect()
+    }
+
+    /// An Array column if the elements of the tuple share a dtype, a Struct
+    /// column of `field_0..field_n` otherwise. Elements that were only ever
+    /// null take the dtype of the others.
+    fn finish_tuple(mut self) -> Result<Series, PlRowSerdeError> {
+        let validity = std::mem::take(&mut self.validity);
+        let fields = self.finish()?;
+        let mut dtypes = fields
+            .iter()
+            .map(Series::dtype)
+            .filter(|dtype| **dtype != DataType::Null);
+        let dtype = match dtypes.next() {
+            Some(first) if dtypes.all(|i| i == first) => first.clone(),
+            Some(_) => return struct_series(fields, validity),
+            None => DataType::Null,
+        };
+
+        // elements row after row
+        let (width, rows) = (fields.len(), validity.len());
+        let mut values = Series::new_empty("", &dtype);
+        for field in fields.iter() {
+            values.append(&field.cast(&dtype)?)?;
+        }
+        let idx: Vec<IdxSize> = (0..rows)
+            .flat_map(|row| (0..width).map(move |i| (i * rows + row) as IdxSize))
+            .collect();
+        let values = values.take(&IdxCa::from_vec("", idx))?;
+
+        let offsets = (0..=rows).map(|row| (row * width) as i64).collect();
+        let lists = list_series(values, offsets, validity)?;
+        // polars has no Arrays of width 0, these stay lists of no elements
+        if width == 0 {
+            return Ok(lists);
+        }
+        Ok(lists.cast(&DataType::Array(Box::new(dtype), width))?)
+    }
+}
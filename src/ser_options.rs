@@ -0,0 +1,211 @@
+use polars::datatypes::TimeUnit;
+
+/// Options for [`crate::serialize_into_dataframe_with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct SerializeOptions {
+    pub(crate) column_names: Vec<String>,
+    pub(crate) enum_layout: EnumLayout,
+    pub(crate) dtype_conflict: DtypeConflict,
+    pub(crate) include: Option<Vec<String>>,
+    pub(crate) exclude: Vec<String>,
+    pub(crate) rename: Option<fn(&str) -> String>,
+    pub(crate) first: Vec<String>,
+    pub(crate) seq_as_array: bool,
+    pub(crate) duration_unit: Option<TimeUnit>,
+    pub(crate) datetime_unit: Option<TimeUnit>,
+    pub(crate) decimal_scale: Option<usize>,
+    pub(crate) int128_layout: Int128Layout,
+}
+
+impl SerializeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name the columns written for tuple and tuple struct rows.
+    ///
+    /// Elements without a name are written to `column_<position>`.
+    pub fn with_column_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.column_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Lay out enum variants with data after `layout`, [`EnumLayout::External`]
+    /// by default.
+    pub fn with_enum_layout(mut self, layout: EnumLayout) -> Self {
+        self.enum_layout = layout;
+        self
+    }
+
+    /// Handle values whose type doesn't match the values written to the column
+    /// before them after `policy`, [`DtypeConflict::Error`] by default.
+    pub fn with_dtype_conflict(mut self, policy: DtypeConflict) -> Self {
+        self.dtype_conflict = policy;
+        self
+    }
+
+    /// Only write the columns of the fields in `names`.
+    ///
+    /// Like the other column options, fields are named as serde writes them,
+    /// before [`SerializeOptions::with_rename`].
+    pub fn with_include<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.include = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Leave out the columns of the fields in `names`. Their values aren't
+    /// serialized at all.
+    pub fn with_exclude<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.exclude = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Name each column after `rename` applied to its field, e.g. to write
+    /// them camelCase.
+    pub fn with_rename(mut self, rename: fn(&str) -> String) -> Self {
+        self.rename = Some(rename);
+        self
+    }
+
+    /// Put the columns of the fields in `names` first, in that order. The
+    /// other columns follow in the order the fields are declared in.
+    pub fn with_first<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.first = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Write seqs like `Vec<T>` to Array columns rather than List ones, e.g.
+    /// for embeddings. Every row of a column must then have the same length,
+    /// or serializing fails with [`crate::PlRowSerdeError::LengthMismatch`].
+    ///
+    /// Arrays like `[T; N]` are always written to Array columns.
+    pub fn with_seq_as_array(mut self, seq_as_array: bool) -> Self {
+        self.seq_as_array = seq_as_array;
+        self
+    }
+
+    /// Write durations like `std::time::Duration` in ticks of `unit`,
+    /// [`TimeUnit::Microseconds`] by default. Finer parts are truncated.
+    pub fn with_duration_unit(mut self, unit: TimeUnit) -> Self {
+        self.duration_unit = Some(unit);
+        self
+    }
+
+    pub(crate) fn duration_unit(&self) -> TimeUnit {
+        self.duration_unit.unwrap_or(TimeUnit::Microseconds)
+    }
+
+    /// Write datetimes like `chrono::NaiveDateTime` in ticks of `unit`,
+    /// [`TimeUnit::Microseconds`] by default. Finer parts are truncated.
+    pub fn with_datetime_unit(mut self, unit: TimeUnit) -> Self {
+        self.datetime_unit = Some(unit);
+        self
+    }
+
+    pub(crate) fn datetime_unit(&self) -> TimeUnit {
+        self.datetime_unit.unwrap_or(TimeUnit::Microseconds)
+    }
+
+    /// Write decimals with `scale` decimals, failing for values with more of
+    /// them. By default the scale of a column grows to fit its values.
+    pub fn with_decimal_scale(mut self, scale: usize) -> Self {
+        self.decimal_scale = Some(scale);
+        self
+    }
+
+    /// Write `i128` and `u128` values after `layout`,
+    /// [`Int128Layout::Decimal`] by default.
+    pub fn with_int128_layout(mut self, layout: Int128Layout) -> Self {
+        self.int128_layout = layout;
+        self
+    }
+
+    /// Whether the column of the field `name` is written.
+    pub(crate) fn keeps(&self, name: &str) -> bool {
+        let included = match &self.include {
+            Some(include) => include.iter().any(|i| i == name),
+            None => true,
+        };
+        included && !self.exclude.iter().any(|i| i == name)
+    }
+}
+
+/// What to do when a row writes a value of another type than the rows before
+/// it, e.g. an `i64` to a column of `i32`s through an untagged enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DtypeConflict {
+    /// Fail with [`crate::PlRowSerdeError::TypeMismatch`], naming the column
+    /// and the row.
+    #[default]
+    Error,
+    /// Cast the column to the supertype of the values, failing like
+    /// [`DtypeConflict::Error`] if there is none.
+    Supertype,
+    /// Cast the column to String.
+    String,
+}
+
+/// How `i128` and `u128` values are written. Each layout is read back without
+/// loss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Int128Layout {
+    /// `Decimal(38, 0)`, failing for values with more than 38 digits.
+    #[default]
+    Decimal,
+    /// String of the digits of the value.
+    String,
+    /// Binary of the 16 big-endian bytes of the value, as polars has no fixed
+    /// size binary dtype.
+    Binary,
+}
+
+/// How enum variants with data are laid out in a Struct column.
+///
+/// Every layout has a String field `tag` holding the name of the variant. Enums
+/// with unit variants only are written to a Categorical column instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumLayout {
+    /// `{tag, <Variant>: payload, ...}`, a nullable field per variant with data.
+    External { tag: &'static str },
+    /// `{tag, ...}` with the fields of struct variants next to the tag, like
+    /// `#[serde(tag = "...")]`. Other payloads go to a field named after the
+    /// variant.
+    Internal { tag: &'static str },
+    /// `{tag, content}` with `content` laid out like [`EnumLayout::Internal`]
+    /// without the tag, like `#[serde(tag = "...", content = "...")]` for
+    /// struct variants.
+    Adjacent {
+        tag: &'static str,
+        content: &'static str,
+    },
+}
+
+impl EnumLayout {
+    pub(crate) fn tag(&self) -> &'static str {
+        match self {
+            Self::External { tag } | Self::Internal { tag } | Self::Adjacent { tag, .. } => tag,
+        }
+    }
+}
+
+impl Default for EnumLayout {
+    fn default() -> Self {
+        Self::External { tag: "variant" }
+    }
+}
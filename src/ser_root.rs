@@ -1,36 +1,38 @@
-use std::collections::HashMap;
+#[cfg(test)]
+use polars::prelude::{AnyValue, NamedFrom};
 
 use polars::{
     frame::DataFrame,
-    prelude::{AnyValue, DataType, NamedFrom},
+    prelude::{
+        BinaryChunkedBuilder, BooleanChunkedBuilder, ChunkedBuilder, PrimitiveChunkedBuilder,
+        StringChunkedBuilder, TimeUnit, TimeZone,
+    },
     series::Series,
 };
 use serde::{
-    de::Error,
     ser::{
-        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
-        SerializeTupleStruct, SerializeTupleVariant,
+        Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+        SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
     },
     Serialize, Serializer,
 };
 
-use crate::pl_row_error::PlRowSerdeError;
-
-pub fn serialize_into_dataframe<T: Serialize>(
-    iter: impl Iterator<Item = T>,
-) -> Result<DataFrame, serde::de::value::Error> {
-    let mut plr = PlRowSerStruct::default();
+use crate::{
+    pl_row_error::PlRowSerdeError,
+    ser_builder::{Column, ColumnBuilder, DecimalColumn, ListColumn, DECIMAL_PRECISION},
+    ser_options::{EnumLayout, Int128Layout, SerializeOptions},
+};
 
-    for i in iter {
-        let res = i.serialize(plr);
-        match res {
-            Ok(a) => {
-                plr = a;
-            }
-            Err(e) => return Err(e),
-        }
-    }
-    Ok(plr.into_dataframe())
+/// Serialize a single value into a column named "", for the tests below.
+#[cfg(test)]
+fn serialize_value<T: Serialize + ?Sized>(value: &T) -> Result<Series, PlRowSerdeError> {
+    let options = SerializeOptions::default();
+    let mut column = Column::new("", 0, 1);
+    value.serialize(PlRowSer {
+        column: &mut column,
+        options: &options,
+    })?;
+    column.finish()
 }
 
 #[test]
@@ -51,53 +53,47 @@ fn test_serialize_into_series_with_options_primitive() {
         option_unit: Option<()>,
     }
     let t = TestStruct::default();
+    assert_eq!(Ok(Series::new_null("", 1)), serialize_value(&t.option_unit));
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_unit.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new_null("", 1)),
-        t.option_boolean.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new_null("", 1)),
-        t.option_int8.serialize(PlRowSer::default())
+        serialize_value(&t.option_boolean)
     );
+    assert_eq!(Ok(Series::new_null("", 1)), serialize_value(&t.option_int8));
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_int16.serialize(PlRowSer::default())
+        serialize_value(&t.option_int16)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_int32.serialize(PlRowSer::default())
+        serialize_value(&t.option_int32)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_int64.serialize(PlRowSer::default())
+        serialize_value(&t.option_int64)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_uint8.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint8)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_uint16.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint16)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_uint32.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint32)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_uint64.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint64)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_utf8_heap.serialize(PlRowSer::default())
+        serialize_value(&t.option_utf8_heap)
     );
     assert_eq!(
         Ok(Series::new_null("", 1)),
-        t.option_utf8_static.serialize(PlRowSer::default())
+        serialize_value(&t.option_utf8_static)
     );
 
     let t = TestStruct {
@@ -114,53 +110,47 @@ fn test_serialize_into_series_with_options_primitive() {
         option_utf8_static: Some(Default::default()),
         option_unit: Some(()),
     };
-    assert_eq!(
-        Ok(Series::new("", [true])),
-        t.option_unit.serialize(PlRowSer::default())
-    );
+    assert_eq!(Ok(Series::new("", [true])), serialize_value(&t.option_unit));
     assert_eq!(
         Ok(Series::new("", [false])),
-        t.option_boolean.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_i8])),
-        t.option_int8.serialize(PlRowSer::default())
+        serialize_value(&t.option_boolean)
     );
+    assert_eq!(Ok(Series::new("", [0_i8])), serialize_value(&t.option_int8));
     assert_eq!(
         Ok(Series::new("", [0_i16])),
-        t.option_int16.serialize(PlRowSer::default())
+        serialize_value(&t.option_int16)
     );
     assert_eq!(
         Ok(Series::new("", [0_i32])),
-        t.option_int32.serialize(PlRowSer::default())
+        serialize_value(&t.option_int32)
     );
     assert_eq!(
         Ok(Series::new("", [0_i64])),
-        t.option_int64.serialize(PlRowSer::default())
+        serialize_value(&t.option_int64)
     );
     assert_eq!(
         Ok(Series::new("", [0_u8])),
-        t.option_uint8.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint8)
     );
     assert_eq!(
         Ok(Series::new("", [0_u16])),
-        t.option_uint16.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint16)
     );
     assert_eq!(
         Ok(Series::new("", [0_u32])),
-        t.option_uint32.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint32)
     );
     assert_eq!(
         Ok(Series::new("", [0_u64])),
-        t.option_uint64.serialize(PlRowSer::default())
+        serialize_value(&t.option_uint64)
     );
     assert_eq!(
         Ok(Series::new("", [""])),
-        t.option_utf8_heap.serialize(PlRowSer::default())
+        serialize_value(&t.option_utf8_heap)
     );
     assert_eq!(
         Ok(Series::new("", [""])),
-        t.option_utf8_static.serialize(PlRowSer::default())
+        serialize_value(&t.option_utf8_static)
     );
 }
 
@@ -184,57 +174,21 @@ fn test_serialize_into_series() {
     }
 
     let t = TestStruct::default();
-    assert_eq!(
-        Ok(Series::new("", [true])),
-        t.unit.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [false])),
-        t.boolean.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_i8])),
-        t.int8.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_i16])),
-        t.int16.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_i32])),
-        t.int32.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_i64])),
-        t.int64.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_u8])),
-        t.uint8.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_u16])),
-        t.uint16.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_u32])),
-        t.uint32.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [0_u64])),
-        t.uint64.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [""])),
-        t.utf8_heap.serialize(PlRowSer::default())
-    );
-    assert_eq!(
-        Ok(Series::new("", [""])),
-        t.utf8_static.serialize(PlRowSer::default())
-    );
+    assert_eq!(Ok(Series::new("", [true])), serialize_value(&t.unit));
+    assert_eq!(Ok(Series::new("", [false])), serialize_value(&t.boolean));
+    assert_eq!(Ok(Series::new("", [0_i8])), serialize_value(&t.int8));
+    assert_eq!(Ok(Series::new("", [0_i16])), serialize_value(&t.int16));
+    assert_eq!(Ok(Series::new("", [0_i32])), serialize_value(&t.int32));
+    assert_eq!(Ok(Series::new("", [0_i64])), serialize_value(&t.int64));
+    assert_eq!(Ok(Series::new("", [0_u8])), serialize_value(&t.uint8));
+    assert_eq!(Ok(Series::new("", [0_u16])), serialize_value(&t.uint16));
+    assert_eq!(Ok(Series::new("", [0_u32])), serialize_value(&t.uint32));
+    assert_eq!(Ok(Series::new("", [0_u64])), serialize_value(&t.uint64));
+    assert_eq!(Ok(Series::new("", [""])), serialize_value(&t.utf8_heap));
+    assert_eq!(Ok(Series::new("", [""])), serialize_value(&t.utf8_static));
     assert_eq!(
         Ok(Series::new("", [(0 as char).to_string()])),
-        t.char.serialize(PlRowSer::default())
+        serialize_value(&t.char)
     );
 }
 
@@ -249,15 +203,15 @@ fn enum_variant_serialize_into_series() {
 
     assert_eq!(
         Ok(Series::new("", ["Lol"])),
-        TestEnum::Lol.serialize(PlRowSer::default())
+        serialize_value(&TestEnum::Lol)
     );
     assert_eq!(
         Ok(Series::new("", ["Wow"])),
-        TestEnum::Wow.serialize(PlRowSer::default())
+        serialize_value(&TestEnum::Wow)
     );
     assert_eq!(
         Ok(Series::new("", ["Asdf"])),
-        TestEnum::Asdf.serialize(PlRowSer::default())
+        serialize_value(&TestEnum::Asdf)
     );
 }
 
@@ -280,10 +234,9 @@ fn test_serialize_df() {
     }
 
     let t = TestStruct::default();
-    let df = t
-        .serialize(PlRowSerStruct::default())
-        .unwrap()
-        .into_dataframe();
+    let mut plr = PlRowSerStruct::default();
+    t.serialize(&mut plr).unwrap();
+    let df = plr.into_dataframe().unwrap();
 
     assert_eq!(df.height(), 1);
     assert_eq!(df.get_columns().len(), 12);
@@ -319,10 +272,9 @@ fn test_serialize_df_list() {
     let t = TestStruct {
         vector_u8: vec![1, 2, 3],
     };
-    let df = t
-        .serialize(PlRowSerStruct::default())
-        .unwrap()
-        .into_dataframe();
+    let mut plr = PlRowSerStruct::default();
+    t.serialize(&mut plr).unwrap();
+    let df = plr.into_dataframe().unwrap();
     println!("{df:?}");
     let df2 = DataFrame::new(vec![Series::new("", [Series::new("", [1u8, 2, 3])])]).unwrap();
     println!("{df2:?}");
@@ -353,10 +305,9 @@ fn test_serialize_df_optional_none() {
     }
 
     let t = TestStruct::default();
-    let df = t
-        .serialize(PlRowSerStruct::default())
-        .unwrap()
-        .into_dataframe();
+    let mut plr = PlRowSerStruct::default();
+    t.serialize(&mut plr).unwrap();
+    let df = plr.into_dataframe().unwrap();
 
     assert_eq!(df.height(), 1);
     assert_eq!(df.get_columns().len(), 13);
@@ -377,251 +328,300 @@ fn test_serialize_df_optional_none() {
     assert_eq!(df["utf8_heap"].get(0).unwrap(), AnyValue::Null);
     assert_eq!(df["utf8_static"].get(0).unwrap(), AnyValue::Null);
 
-    // if there is no value, it becomes null
-    assert_eq!(df["vector_u8"].get(0).unwrap(), AnyValue::Null);
-}
-
-#[derive(Default, Debug)]
-struct PlRowSer {
-    pl_ser: Series,
+    // an empty vec is an empty list rather than null
+    let vector_u8 = df["vector_u8"].list().unwrap();
+    assert_eq!(vector_u8.get_as_series(0).map(|i| i.len()), Some(0));
 }
 
-#[derive(Default)]
-struct PlSerPlaceHolder;
-
-#[derive(Default, Debug)]
-struct PlRowSerSeq {
-    parent_ser: PlRowSer,
-    pl_ser: Option<PlRowSer>,
+/// Writes a value to a column, typing the column after the first value that
+/// isn't null.
+struct PlRowSer<'a> {
+    column: &'a mut Column,
+    options: &'a SerializeOptions,
 }
-impl SerializeSeq for PlRowSerSeq {
-    type Ok = Series;
-
-    type Error = crate::PlRowSerdeError;
-
-    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        let pl_ser = value.serialize(PlRowSer::default())?;
-        match self.pl_ser.as_mut() {
-            Some(i) => {
-                i.pl_ser.extend(&pl_ser).unwrap();
-            }
-            None => {
-                self.pl_ser = Some(PlRowSer { pl_ser });
-            }
-        };
-        Ok(())
-    }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        let other = if self.pl_ser.is_none() {
-            Series::new_null(&self.parent_ser.pl_ser.name(), 0)
-        } else {
-            self.pl_ser.unwrap().pl_ser
-        };
-
-        let mut pl_ser = self.parent_ser.pl_ser;
-        println!("{pl_ser}");
-        println!("{other}");
-        if pl_ser.dtype() == &DataType::Null && pl_ser.len() == 0 && pl_ser.dtype() != other.dtype()
-        {
-            pl_ser = pl_ser
-                .cast(&DataType::List(other.dtype().clone().boxed()))
-                .unwrap();
+/// Picks the builder of the `$variant` for [`Column::typed`].
+macro_rules! builder_of {
+    ($variant: ident) => {
+        |builder| match builder {
+            ColumnBuilder::$variant(b) => Some(b),
+            _ => None,
         }
-
-        pl_ser.extend(&Series::new("", [other])).unwrap();
-        Ok(pl_ser)
-    }
+    };
 }
 
-impl SerializeMap for PlSerPlaceHolder {
-    type Ok = Series;
-    type Error = crate::PlRowSerdeError;
-
-    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
-    }
-
-    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
+impl<'a> PlRowSer<'a> {
+    /// Type the column as a list to write a row of it, see [`ListColumn`].
+    fn list(self, expected: &'static str, array: bool) -> Result<PlRowSerSeq<'a>, PlRowSerdeError> {
+        self.column.typed(
+            self.options.dtype_conflict,
+            expected,
+            |capacity| ColumnBuilder::List(Box::new(ListColumn::new(capacity, array))),
+            builder_of!(List),
+        )?;
+        let Column { builder, len, .. } = self.column;
+        match builder {
+            ColumnBuilder::List(list) => Ok(PlRowSerSeq {
+                list,
+                len,
+                options: self.options,
+            }),
+            other => Err(PlRowSerdeError::type_mismatch(expected, &other.dtype())),
+        }
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    fn append_str(self, v: &str, expected: &'static str) -> Result<(), PlRowSerdeError> {
+        self.column
+            .typed(
+                self.options.dtype_conflict,
+                expected,
+                |capacity| ColumnBuilder::String(StringChunkedBuilder::new("", capacity)),
+                builder_of!(String),
+            )?
+            .append_value(v);
+        self.column.len += 1;
+        Ok(())
     }
-}
-
-impl SerializeTuple for PlSerPlaceHolder {
-    type Ok = Series;
 
-    type Error = PlRowSerdeError;
-
-    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
+    /// Write a duration of `nanos` to a Duration column, in the unit of the
+    /// options.
+    fn append_duration(self, nanos: i128) -> Result<(), PlRowSerdeError> {
+        let unit = self.options.duration_unit();
+        let ticks = i64::try_from(nanos / i128::from(nanos_per_tick(unit))).map_err(|_| {
+            <PlRowSerdeError as serde::ser::Error>::custom(format!(
+                "duration of {nanos}ns out of the range of Duration({unit})"
+            ))
+        })?;
+        self.column
+            .typed(
+                self.options.dtype_conflict,
+                "duration",
+                |capacity| {
+                    ColumnBuilder::Duration(PrimitiveChunkedBuilder::new("", capacity), unit)
+                },
+                |builder| match builder {
+                    ColumnBuilder::Duration(b, u) if *u == unit => Some(b),
+                    _ => None,
+                },
+            )?
+            .append_value(ticks);
+        self.column.len += 1;
+        Ok(())
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    fn append_bytes(self, v: &[u8], expected: &'static str) -> Result<(), PlRowSerdeError> {
+        self.column
+            .typed(
+                self.options.dtype_conflict,
+                expected,
+                |capacity| ColumnBuilder::Binary(BinaryChunkedBuilder::new("", capacity)),
+                builder_of!(Binary),
+            )?
+            .append_value(v);
+        self.column.len += 1;
+        Ok(())
     }
-}
-impl SerializeTupleStruct for PlSerPlaceHolder {
-    type Ok = Series;
-
-    type Error = PlRowSerdeError;
 
-    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
+    /// Write `unscaled` with `scale` decimals to a Decimal column of the
+    /// `fixed` scale, or else one growing to fit its values.
+    fn append_decimal(
+        self,
+        unscaled: i128,
+        scale: usize,
+        fixed: Option<usize>,
+    ) -> Result<(), PlRowSerdeError> {
+        self.column
+            .typed(
+                self.options.dtype_conflict,
+                "decimal",
+                |capacity| ColumnBuilder::Decimal(DecimalColumn::new(capacity, fixed)),
+                builder_of!(Decimal),
+            )?
+            .append_value(unscaled, scale)?;
+        self.column.len += 1;
+        Ok(())
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    /// Write a value wrapped by the `temporal` module to a temporal column: the
+    /// days of a date since the epoch, the nanoseconds of a time since
+    /// midnight or of a datetime since the epoch.
+    fn append_temporal(self, name: &'static str, value: i128) -> Result<(), PlRowSerdeError> {
+        let out_of_range = |dtype: String| {
+            <PlRowSerdeError as serde::ser::Error>::custom(format!(
+                "{name} {value} out of the range of {dtype}"
+            ))
+        };
+        let conflict = self.options.dtype_conflict;
+        match name {
+            DATE => {
+                let days = i32::try_from(value).map_err(|_| out_of_range("Date".into()))?;
+                self.column
+                    .typed(
+                        conflict,
+                        "date",
+                        |capacity| ColumnBuilder::Date(PrimitiveChunkedBuilder::new("", capacity)),
+                        builder_of!(Date),
+                    )?
+                    .append_value(days);
+            }
+            TIME => {
+                let nanos = i64::try_from(value).map_err(|_| out_of_range("Time".into()))?;
+                self.column
+                    .typed(
+                        conflict,
+                        "time",
+                        |capacity| ColumnBuilder::Time(PrimitiveChunkedBuilder::new("", capacity)),
+                        builder_of!(Time),
+                    )?
+                    .append_value(nanos);
+            }
+            _ => {
+                // zoned values are written in UTC, like polars stores them,
+                // so the offset of a `DateTime<FixedOffset>` is lost
+                let zoned = name == DATETIME_UTC;
+                let tz = zoned.then(|| TimeZone::from("UTC"));
+                let unit = self.options.datetime_unit();
+                let ticks = i64::try_from(value.div_euclid(i128::from(nanos_per_tick(unit))))
+                    .map_err(|_| out_of_range(format!("Datetime({unit})")))?;
+                self.column
+                    .typed(
+                        conflict,
+                        "datetime",
+                        |capacity| {
+                            ColumnBuilder::Datetime(
+                                PrimitiveChunkedBuilder::new("", capacity),
+                                unit,
+                                tz,
+                            )
+                        },
+                        |builder| match builder {
+                            ColumnBuilder::Datetime(b, _, tz) if tz.is_some() == zoned => Some(b),
+                            _ => None,
+                        },
+                    )?
+                    .append_value(ticks);
+            }
+        }
+        self.column.len += 1;
+        Ok(())
     }
 }
-impl SerializeTupleVariant for PlSerPlaceHolder {
-    type Ok = Series;
-
-    type Error = PlRowSerdeError;
 
-    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+/// Names of the newtype structs the `temporal` module wraps chrono values in,
+/// telling [`PlRowSer`] to write them to temporal columns.
+pub(crate) const DATE: &str = "$polars_deser_row::Date";
+pub(crate) const DATETIME: &str = "$polars_deser_row::Datetime";
+pub(crate) const DATETIME_UTC: &str = "$polars_deser_row::DatetimeUtc";
+pub(crate) const TIME: &str = "$polars_deser_row::Time";
+/// Wraps the nanoseconds of a duration.
+pub(crate) const DURATION: &str = "$polars_deser_row::Duration";
+/// Wraps a decimal, its unscaled value and scale.
+pub(crate) const DECIMAL: &str = "$polars_deser_row::Decimal";
+
+pub(crate) fn nanos_per_tick(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
     }
 }
-impl SerializeStruct for PlSerPlaceHolder {
-    type Ok = Series;
-
-    type Error = PlRowSerdeError;
 
-    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
-    }
+/// The name of the tuple element at `idx` written to a nested column.
+fn field_name(_: &SerializeOptions, idx: usize) -> String {
+    format!("field_{idx}")
 }
-impl SerializeStructVariant for PlSerPlaceHolder {
-    type Ok = Series;
 
-    type Error = PlRowSerdeError;
-
-    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        todo!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+/// The name of the column for the tuple element at `idx` of a row.
+fn column_name(options: &SerializeOptions, idx: usize) -> String {
+    match options.column_names.get(idx) {
+        Some(name) => name.clone(),
+        None => format!("column_{idx}"),
     }
 }
 
 macro_rules! impl_serialize_func {
-    ($func: ident, $variant_dt: ident, $arg_dt: ident) => {
-        fn $func(mut self, v: $arg_dt) -> Result<Self::Ok, Self::Error> {
-            if self.pl_ser.dtype() == &DataType::Null {
-                self.pl_ser = self.pl_ser.cast(&DataType::$variant_dt).unwrap();
-            }
-            self.pl_ser
-                .extend(&Series::new(self.pl_ser.name(), [v]))
-                .unwrap();
-            Ok(self.pl_ser)
+    ($func: ident, $variant: ident, $builder: ident, $arg_dt: ident) => {
+        fn $func(self, v: $arg_dt) -> Result<Self::Ok, Self::Error> {
+            self.column
+                .typed(
+                    self.options.dtype_conflict,
+                    stringify!($arg_dt),
+                    |capacity| ColumnBuilder::$variant($builder::new("", capacity)),
+                    builder_of!($variant),
+                )?
+                .append_value(v);
+            self.column.len += 1;
+            Ok(())
         }
     };
 }
-impl<'a> Serializer for PlRowSer {
-    type Ok = Series;
+
+impl<'a> Serializer for PlRowSer<'a> {
+    type Ok = ();
 
     type Error = crate::pl_row_error::PlRowSerdeError;
 
-    type SerializeSeq = PlRowSerSeq;
+    type SerializeSeq = PlRowSerSeq<'a>;
 
-    type SerializeTuple = PlSerPlaceHolder;
+    type SerializeTuple = PlRowSerFields<'a>;
 
-    type SerializeTupleStruct = PlSerPlaceHolder;
+    type SerializeTupleStruct = PlRowSerFields<'a>;
 
-    type SerializeTupleVariant = PlSerPlaceHolder;
+    type SerializeTupleVariant = PlRowSerVariant<'a>;
 
-    type SerializeMap = PlSerPlaceHolder;
+    type SerializeMap = PlRowSerSeq<'a>;
 
-    type SerializeStruct = PlSerPlaceHolder;
+    type SerializeStruct = PlRowSerFields<'a>;
 
-    type SerializeStructVariant = PlSerPlaceHolder;
-    impl_serialize_func!(serialize_bool, Boolean, bool);
-    impl_serialize_func!(serialize_u8, UInt8, u8);
-    impl_serialize_func!(serialize_u16, UInt16, u16);
-    impl_serialize_func!(serialize_u32, UInt32, u32);
-    impl_serialize_func!(serialize_u64, UInt64, u64);
-    impl_serialize_func!(serialize_i8, Int8, i8);
-    impl_serialize_func!(serialize_i16, Int16, i16);
-    impl_serialize_func!(serialize_i32, Int32, i32);
-    impl_serialize_func!(serialize_i64, Int64, i64);
-    impl_serialize_func!(serialize_f32, Float32, f32);
-    impl_serialize_func!(serialize_f64, Float64, f64);
+    type SerializeStructVariant = PlRowSerVariant<'a>;
+    impl_serialize_func!(serialize_bool, Boolean, BooleanChunkedBuilder, bool);
+    impl_serialize_func!(serialize_u8, UInt8, PrimitiveChunkedBuilder, u8);
+    impl_serialize_func!(serialize_u16, UInt16, PrimitiveChunkedBuilder, u16);
+    impl_serialize_func!(serialize_u32, UInt32, PrimitiveChunkedBuilder, u32);
+    impl_serialize_func!(serialize_u64, UInt64, PrimitiveChunkedBuilder, u64);
+    impl_serialize_func!(serialize_i8, Int8, PrimitiveChunkedBuilder, i8);
+    impl_serialize_func!(serialize_i16, Int16, PrimitiveChunkedBuilder, i16);
+    impl_serialize_func!(serialize_i32, Int32, PrimitiveChunkedBuilder, i32);
+    impl_serialize_func!(serialize_i64, Int64, PrimitiveChunkedBuilder, i64);
+    impl_serialize_func!(serialize_f32, Float32, PrimitiveChunkedBuilder, f32);
+    impl_serialize_func!(serialize_f64, Float64, PrimitiveChunkedBuilder, f64);
 
-    fn serialize_char(mut self, v: char) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = self.pl_ser.cast(&DataType::String).unwrap();
-        }
-        self.pl_ser
-            .append(&Series::new(self.pl_ser.name(), [v.to_string()]))
-            .unwrap();
-        Ok(self.pl_ser)
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.append_str(v.encode_utf8(&mut [0; 4]), "char")
     }
 
-    fn serialize_str(mut self, v: &str) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = self.pl_ser.cast(&DataType::String).unwrap();
-        }
-        self.pl_ser
-            .append(&Series::new(self.pl_ser.name(), [v]))
-            .unwrap();
-        Ok(self.pl_ser)
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.append_str(v, "str")
     }
 
-    fn serialize_bytes(mut self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = Series::new_null(self.pl_ser.name(), self.pl_ser.len() + 1);
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        match self.options.int128_layout {
+            Int128Layout::Decimal => self.append_decimal(v, 0, Some(0)),
+            Int128Layout::String => self.append_str(&v.to_string(), "i128"),
+            Int128Layout::Binary => self.append_bytes(&v.to_be_bytes(), "i128"),
         }
-        let mut seq = self.serialize_seq(Some(v.len())).unwrap();
-        for i in v {
-            seq.serialize_element(i)?;
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        match self.options.int128_layout {
+            Int128Layout::Decimal => match i128::try_from(v) {
+                Ok(v) => self.append_decimal(v, 0, Some(0)),
+                Err(_) => Err(<PlRowSerdeError as serde::ser::Error>::custom(format!(
+                    "u128 {v} doesn't fit Decimal({DECIMAL_PRECISION}, 0)"
+                ))),
+            },
+            Int128Layout::String => self.append_str(&v.to_string(), "u128"),
+            Int128Layout::Binary => self.append_bytes(&v.to_be_bytes(), "u128"),
         }
-        let ser = seq.end().unwrap();
+    }
 
-        Ok(ser)
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.append_bytes(v, "bytes")
     }
 
-    fn serialize_none(mut self) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = Series::new_null(self.pl_ser.name(), self.pl_ser.len() + 1);
-        }
-        Ok(self.pl_ser)
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.column.append_null();
+        Ok(())
     }
 
     fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
@@ -631,102 +631,120 @@ impl<'a> Serializer for PlRowSer {
         value.serialize(self)
     }
 
-    fn serialize_unit(mut self) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = self.pl_ser.cast(&DataType::Boolean).unwrap();
-        }
-        self.pl_ser
-            .extend(&Series::new(self.pl_ser.name(), [true]))
-            .unwrap();
-        Ok(self.pl_ser)
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.serialize_bool(true)
     }
 
-    fn serialize_unit_struct(mut self, name: &'static str) -> Result<Self::Ok, Self::Error> {
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = self.pl_ser.cast(&DataType::String).unwrap();
-        }
-        self.pl_ser
-            .extend_constant(AnyValue::String(name), 1)
-            .map_err(|e| PlRowSerdeError::custom(e.to_string()))
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.append_str(name, "unit struct")
     }
 
     fn serialize_unit_variant(
-        mut self,
+        self,
         _: &'static str,
         _: u32,
         variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        let cat = &DataType::Categorical(None, Default::default());
-        if self.pl_ser.dtype() == &DataType::Null {
-            self.pl_ser = self.pl_ser.cast(cat).unwrap();
+        // the enum has variants with data, so this is a row with the tag only
+        if let ColumnBuilder::Struct(_) = self.column.builder {
+            return PlRowSerVariant::begin(self, variant, VariantKind::Unit)?.finish();
         }
-        self.pl_ser
-            .extend(
-                &Series::new(self.pl_ser.name(), [variant])
-                    .cast(cat)
-                    .unwrap(),
-            )
-            .unwrap();
-        Ok(self.pl_ser)
+        self.column
+            .typed(
+                self.options.dtype_conflict,
+                "unit variant",
+                |capacity| ColumnBuilder::Categorical(StringChunkedBuilder::new("", capacity)),
+                builder_of!(Categorical),
+            )?
+            .append_value(variant);
+        self.column.len += 1;
+        Ok(())
     }
 
     fn serialize_newtype_struct<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(self)
+        if !matches!(
+            name,
+            DATE | DATETIME | DATETIME_UTC | TIME | DURATION | DECIMAL
+        ) {
+            return value.serialize(self);
+        }
+        match (name, value.serialize(IntegerSer)?) {
+            (DURATION, Integers::One(nanos, _)) => self.append_duration(nanos),
+            (DECIMAL, Integers::Pair(unscaled, scale @ 0..)) => {
+                let fixed = self.options.decimal_scale;
+                self.append_decimal(unscaled, scale as usize, fixed)
+            }
+            (DATE | DATETIME | DATETIME_UTC | TIME, Integers::One(value, _)) => {
+                self.append_temporal(name, value)
+            }
+            _ => Err(<PlRowSerdeError as serde::ser::Error>::custom(format!(
+                "invalid value for {name}"
+            ))),
+        }
     }
 
     fn serialize_newtype_variant<T>(
         self,
-        name: &'static str,
-        variant_index: u32,
+        _name: &'static str,
+        _variant_index: u32,
         variant: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        // tried with struct but it kinda didn't work
-        todo!()
+        let mut ser = PlRowSerVariant::begin(self, variant, VariantKind::Newtype)?;
+        let (options, row_idx) = (ser.options, ser.column.len);
+        let column = ser
+            .container()?
+            .variant_field(options.dtype_conflict, variant, "enum")?;
+        value
+            .serialize(PlRowSer { column, options })
+            .map_err(|e| e.within(variant, row_idx))?;
+        ser.finish()
     }
 
     fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        let seq = PlRowSerSeq {
-            pl_ser: None,
-            parent_ser: self,
-        };
-        Ok(seq)
+        self.list("seq", self.options.seq_as_array)
     }
 
-    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        todo!()
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        // elements go to `field_0`, `field_1`, ..., turned into an Array
+        // column on finish if they share a dtype
+        self.column
+            .struct_fields(self.options.dtype_conflict, "tuple")?
+            .tuple = true;
+        Ok(PlRowSerFields::new(self.column, self.options, field_name))
     }
 
     fn serialize_tuple_struct(
         self,
-        name: &'static str,
+        _name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        todo!()
+        self.serialize_tuple(len)
     }
 
     fn serialize_tuple_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
+        _name: &'static str,
+        _variant_index: u32,
         variant: &'static str,
-        len: usize,
+        _len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        todo!()
+        PlRowSerVariant::begin(self, variant, VariantKind::Tuple)
     }
 
-    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        todo!()
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        // a list of `{key, value}` structs
+        self.list("map", false)
     }
 
     fn serialize_struct(
@@ -734,198 +752,739 @@ impl<'a> Serializer for PlRowSer {
         name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        todo!()
+        // `std::time::Duration` is a `{secs: u64, nanos: u32}` struct to serde,
+        // other structs named so are told apart by the names and types of
+        // their fields as they come, see `PlRowSerFields::not_a_duration`
+        if name == "Duration" && len == 2 {
+            return Ok(PlRowSerFields {
+                duration: Some(DurationFields::Start),
+                ..PlRowSerFields::new(self.column, self.options, field_name)
+            });
+        }
+        self.column
+            .struct_fields(self.options.dtype_conflict, "struct")?;
+        Ok(PlRowSerFields::new(self.column, self.options, field_name))
     }
 
     fn serialize_struct_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
+        _name: &'static str,
+        _variant_index: u32,
         variant: &'static str,
-        len: usize,
+        _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        todo!()
+        PlRowSerVariant::begin(self, variant, VariantKind::Struct)
     }
 }
 
-#[derive(Default, Debug)]
-pub struct PlRowSerStruct {
-    pl_ser_map: HashMap<&'static str, PlRowSer>,
+/// Writes the elements of a seq to the inner column of a list column, one
+/// list row per seq. The entries of a map are `{key, value}` structs.
+pub struct PlRowSerSeq<'a> {
+    list: &'a mut ListColumn,
+    /// Rows of the list column.
+    len: &'a mut usize,
+    options: &'a SerializeOptions,
 }
 
-impl PlRowSerStruct {
-    pub fn into_dataframe(self) -> DataFrame {
-        let mut max_length = 0;
-        let mut columns: Vec<_> = self
-            .pl_ser_map
-            .into_iter()
-            .map(|(name, val)| {
-                if val.pl_ser.len() > max_length {
-                    max_length = val.pl_ser.len()
-                }
-                val.pl_ser
-            })
-            .collect();
-        columns.iter_mut().for_each(|ser| {
-            println!("{:#?}", ser.name());
-            if ser.len() != max_length && ser.dtype() == &DataType::Null {
-                *ser = Series::new_null(ser.name(), max_length);
-            }
-        });
-        DataFrame::new(columns).unwrap()
+impl SerializeSeq for PlRowSerSeq<'_> {
+    type Ok = ();
+
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(PlRowSer {
+            column: &mut self.list.inner,
+            options: self.options,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.list.end_row()?;
+        *self.len += 1;
+        Ok(())
     }
 }
-pub struct PlSerPlaceHolder2;
-macro_rules! impl_as_todo {
-    ($($func: ident, $arg: ty;)*) => {
-        $(
-            fn $func(self, _: $arg) -> Result<Self::Ok, Self::Error> {
-                todo!()
-            }
-        ) *
 
-    };
+impl PlRowSerSeq<'_> {
+    fn serialize_entry_field<T>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), PlRowSerdeError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let row_idx = *self.len;
+        let column = self
+            .list
+            .inner
+            .field(self.options.dtype_conflict, name, "map")?;
+        value
+            .serialize(PlRowSer {
+                column,
+                options: self.options,
+            })
+            .map_err(|e| e.within(name, row_idx))
+    }
 }
 
-impl SerializeMap for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
+impl SerializeMap for PlRowSerSeq<'_> {
+    type Ok = ();
+
     type Error = crate::PlRowSerdeError;
 
     fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        self.serialize_entry_field("key", key)
     }
 
     fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        self.serialize_entry_field("value", value)?;
+        self.list.inner.end_struct_row()
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        SerializeSeq::end(self)
     }
 }
-impl SerializeTuple for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
 
-    type Error = PlRowSerdeError;
+/// Writes the fields of a struct, or the elements of a tuple, to the fields of
+/// a struct column. Rows are the columns of the frame.
+pub struct PlRowSerFields<'a> {
+    column: &'a mut Column,
+    options: &'a SerializeOptions,
+    /// Position of the next tuple element.
+    tuple_idx: usize,
+    /// Names the fields written for tuple elements.
+    name: fn(&SerializeOptions, usize) -> String,
+    /// Whether the fields are the columns of the frame, which leave out the
+    /// fields excluded by the options.
+    row: bool,
+    /// Key of the map entry being written, for map rows.
+    key: Option<String>,
+    /// Fields of what may be a `std::time::Duration`, until it turns out
+    /// not to be one.
+    duration: Option<DurationFields>,
+}
 
-    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+/// Fields of a `std::time::Duration` written so far.
+#[derive(Clone, Copy)]
+enum DurationFields {
+    Start,
+    Secs(u64),
+    Done(u64, u32),
+}
+
+impl<'a> PlRowSerFields<'a> {
+    fn new(
+        column: &'a mut Column,
+        options: &'a SerializeOptions,
+        name: fn(&SerializeOptions, usize) -> String,
+    ) -> Self {
+        Self {
+            column,
+            options,
+            tuple_idx: 0,
+            name,
+            row: false,
+            key: None,
+            duration: None,
+        }
+    }
+
+    fn row(
+        column: &'a mut Column,
+        options: &'a SerializeOptions,
+        name: fn(&SerializeOptions, usize) -> String,
+    ) -> Self {
+        Self {
+            row: true,
+            ..Self::new(column, options, name)
+        }
+    }
+
+    fn serialize_named<T>(&mut self, key: &str, value: &T) -> Result<(), PlRowSerdeError>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        if self.row && !self.options.keeps(key) {
+            return Ok(());
+        }
+        let row_idx = self.column.len;
+        let column = self
+            .column
+            .field(self.options.dtype_conflict, key, "struct")?;
+        value
+            .serialize(PlRowSer {
+                column,
+                options: self.options,
+            })
+            .map_err(|e| e.within(key, row_idx))
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    /// Write a struct named `Duration` that isn't a `std::time::Duration` as
+    /// any other, with the `secs` it may have started with.
+    fn not_a_duration(&mut self) -> Result<(), PlRowSerdeError> {
+        let Some(duration) = self.duration.take() else {
+            return Ok(());
+        };
+        self.column
+            .struct_fields(self.options.dtype_conflict, "struct")?;
+        match duration {
+            DurationFields::Start => Ok(()),
+            DurationFields::Secs(secs) => self.serialize_named("secs", &secs),
+            DurationFields::Done(secs, nanos) => {
+                self.serialize_named("secs", &secs)?;
+                self.serialize_named("nanos", &nanos)
+            }
+        }
     }
-}
-impl SerializeTupleStruct for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
 
-    type Error = PlRowSerdeError;
-
-    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_positional<T>(&mut self, value: &T) -> Result<(), PlRowSerdeError>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
-    }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        let row_idx = self.column.len;
+        let (options, name) = (self.options, self.name);
+        let idx = self.tuple_idx;
+        self.tuple_idx += 1;
+        let column = self
+            .column
+            .positional(options.dtype_conflict, idx, "tuple", |idx| {
+                name(options, idx)
+            })?;
+        let res = value.serialize(PlRowSer {
+            column: &mut *column,
+            options,
+        });
+        res.map_err(|e| e.within(&column.name, row_idx))
     }
 }
-impl SerializeTupleVariant for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
 
-    type Error = PlRowSerdeError;
+impl SerializeStruct for PlRowSerFields<'_> {
+    type Ok = ();
 
-    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        let Some(duration) = self.duration else {
+            return self.serialize_named(key, value);
+        };
+        let parsed = match (duration, key, value.serialize(IntegerSer)) {
+            (DurationFields::Start, "secs", Ok(Integers::One(secs, "u64"))) => {
+                Some(DurationFields::Secs(secs as u64))
+            }
+            (DurationFields::Secs(secs), "nanos", Ok(Integers::One(nanos, "u32"))) => {
+                Some(DurationFields::Done(secs, nanos as u32))
+            }
+            _ => None,
+        };
+        match parsed {
+            Some(duration) => {
+                self.duration = Some(duration);
+                Ok(())
+            }
+            None => {
+                self.not_a_duration()?;
+                self.serialize_named(key, value)
+            }
+        }
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    fn end(mut self) -> Result<Self::Ok, Self::Error> {
+        match self.duration {
+            Some(DurationFields::Done(secs, nanos)) => PlRowSer {
+                column: self.column,
+                options: self.options,
+            }
+            .append_duration(i128::from(secs) * 1_000_000_000 + i128::from(nanos)),
+            _ => {
+                self.not_a_duration()?;
+                self.column.end_struct_row()
+            }
+        }
     }
 }
-impl SerializeStruct for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
 
-    type Error = PlRowSerdeError;
+impl SerializeTuple for PlRowSerFields<'_> {
+    type Ok = ();
 
-    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        self.serialize_positional(value)
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        self.column.end_struct_row()
     }
 }
-impl SerializeStructVariant for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
 
-    type Error = PlRowSerdeError;
+impl SerializeTupleStruct for PlRowSerFields<'_> {
+    type Ok = ();
+
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.serialize_positional(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.column.end_struct_row()
+    }
+}
+
+impl SerializeMap for PlRowSerFields<'_> {
+    type Ok = ();
+
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key = Some(key.serialize(MapKeySer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self.key.take() {
+            Some(key) => self.serialize_named(&key, value),
+            None => Err(<PlRowSerdeError as serde::ser::Error>::custom(
+                "map value written before its key",
+            )),
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.column.end_struct_row()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VariantKind {
+    Unit,
+    Newtype,
+    Tuple,
+    Struct,
+}
+
+/// Writes a row of an enum with data, laid out after [`EnumLayout`].
+pub struct PlRowSerVariant<'a> {
+    column: &'a mut Column,
+    options: &'a SerializeOptions,
+    variant: &'static str,
+    kind: VariantKind,
+    /// Position of the next tuple element.
+    tuple_idx: usize,
+}
+
+impl<'a> PlRowSerVariant<'a> {
+    /// Type the column as the struct of the enum and write the tag.
+    fn begin(
+        ser: PlRowSer<'a>,
+        variant: &'static str,
+        kind: VariantKind,
+    ) -> Result<Self, PlRowSerdeError> {
+        let PlRowSer { column, options } = ser;
+        let tag = options.enum_layout.tag();
+
+        column.unit_variants_into_struct(tag);
+        PlRowSer {
+            column: column.variant_field(options.dtype_conflict, tag, "enum")?,
+            options,
+        }
+        .append_str(variant, "enum")?;
+
+        Ok(Self {
+            column,
+            options,
+            variant,
+            kind,
+            tuple_idx: 0,
+        })
+    }
+
+    /// The struct column the data goes to, the enum column itself unless the
+    /// layout is adjacent.
+    fn container(&mut self) -> Result<&mut Column, PlRowSerdeError> {
+        match self.options.enum_layout {
+            EnumLayout::Adjacent { content, .. } => {
+                self.column
+                    .variant_field(self.options.dtype_conflict, content, "enum")
+            }
+            _ => Ok(&mut *self.column),
+        }
+    }
+
+    /// Whether the data is a struct of its own, in a field named after the
+    /// variant, rather than fields of the container.
+    fn nested(&self) -> bool {
+        match self.kind {
+            VariantKind::Tuple => true,
+            VariantKind::Struct => matches!(self.options.enum_layout, EnumLayout::External { .. }),
+            VariantKind::Unit | VariantKind::Newtype => false,
+        }
+    }
+
+    fn finish(mut self) -> Result<(), PlRowSerdeError> {
+        let (variant, on_conflict) = (self.variant, self.options.dtype_conflict);
+        if self.nested() {
+            self.container()?
+                .variant_field(on_conflict, variant, "enum")?
+                .end_struct_row()?;
+        }
+        if let EnumLayout::Adjacent { content, .. } = self.options.enum_layout {
+            // unit variants have no content
+            if self.kind != VariantKind::Unit {
+                self.column
+                    .variant_field(on_conflict, content, "enum")?
+                    .end_struct_row()?;
+            }
+        }
+        self.column.end_struct_row()
+    }
+}
+
+impl SerializeTupleVariant for PlRowSerVariant<'_> {
+    type Ok = ();
+
+    type Error = crate::PlRowSerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let (options, variant, row_idx) = (self.options, self.variant, self.column.len);
+        let idx = self.tuple_idx;
+        self.tuple_idx += 1;
+        let column = self
+            .container()?
+            .variant_field(options.dtype_conflict, variant, "enum")?
+            .positional(options.dtype_conflict, idx, "tuple variant", |idx| {
+                field_name(options, idx)
+            })?;
+        value
+            .serialize(PlRowSer { column, options })
+            .map_err(|e| e.within(&field_name(options, idx), row_idx))
+            .map_err(|e| e.within(variant, row_idx))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for PlRowSerVariant<'_> {
+    type Ok = ();
+
+    type Error = crate::PlRowSerdeError;
 
     fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        let (options, variant, row_idx) = (self.options, self.variant, self.column.len);
+        let nested = self.nested();
+        let mut data = self.container()?;
+        if nested {
+            data = data.variant_field(options.dtype_conflict, variant, "enum")?;
+        }
+        // the fields of a struct variant inlined in the container are shared
+        // with the other variants
+        let column = if nested {
+            data.field(options.dtype_conflict, key, "struct variant")?
+        } else {
+            data.variant_field(options.dtype_conflict, key, "struct variant")?
+        };
+        value
+            .serialize(PlRowSer { column, options })
+            .map_err(|e| e.within(key, row_idx))
+            .map_err(|e| e.within(variant, row_idx))
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        self.finish()
+    }
+}
+
+/// Builds a dataframe out of rows, see [`crate::serialize_into_dataframe`].
+///
+/// Rows are the fields of a struct column, whose fields become the columns of
+/// the frame.
+pub struct PlRowSerStruct {
+    root: Column,
+    options: SerializeOptions,
+}
+
+impl Default for PlRowSerStruct {
+    fn default() -> Self {
+        Self::with_options(SerializeOptions::default(), 0)
     }
 }
-impl SerializeSeq for PlSerPlaceHolder2 {
-    type Ok = PlRowSerStruct;
+
+impl PlRowSerStruct {
+    /// Builder for about `capacity` rows.
+    pub fn with_options(options: SerializeOptions, capacity: usize) -> Self {
+        Self {
+            root: Column::new("", 0, capacity),
+            options,
+        }
+    }
+
+    pub fn into_dataframe(self) -> Result<DataFrame, PlRowSerdeError> {
+        let columns = match self.root.builder {
+            ColumnBuilder::Struct(fields) => fields.finish()?,
+            _ => vec![],
+        };
+        // tuple elements aren't filtered while they're written, their
+        // columns are positional
+        let mut columns: Vec<Series> = columns
+            .into_iter()
+            .filter(|i| self.options.keeps(i.name()))
+            .collect();
+        let first = &self.options.first;
+        columns.sort_by_key(|i| {
+            first
+                .iter()
+                .position(|name| name == i.name())
+                .unwrap_or(first.len())
+        });
+        if let Some(rename) = self.options.rename {
+            for column in columns.iter_mut() {
+                let name = rename(column.name());
+                column.rename(&name);
+            }
+        }
+        Ok(DataFrame::new(columns)?)
+    }
+}
+
+/// A row has to be a struct, so it can't be any of these.
+macro_rules! impl_as_unsupported {
+    ($($func: ident, $arg: ty, $found: literal;)*) => {
+        $(
+            fn $func(self, _: $arg) -> Result<Self::Ok, Self::Error> {
+                Err(PlRowSerdeError::unsupported_type($found))
+            }
+        ) *
+
+    };
+}
+
+impl<'a> Serializer for &'a mut PlRowSerStruct {
+    type Ok = ();
 
     type Error = crate::pl_row_error::PlRowSerdeError;
+    type SerializeSeq = Impossible<(), PlRowSerdeError>;
 
-    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    type SerializeTuple = PlRowSerFields<'a>;
+
+    type SerializeTupleStruct = PlRowSerFields<'a>;
+
+    type SerializeTupleVariant = Impossible<(), PlRowSerdeError>;
+
+    type SerializeMap = PlRowSerFields<'a>;
+
+    type SerializeStruct = PlRowSerFields<'a>;
+
+    type SerializeStructVariant = Impossible<(), PlRowSerdeError>;
+
+    impl_as_unsupported!(
+        serialize_bool, bool, "bool";
+        serialize_i8, i8, "i8";
+        serialize_i16, i16, "i16";
+        serialize_i32, i32, "i32";
+        serialize_i64, i64, "i64";
+        serialize_u8, u8, "u8";
+        serialize_u16, u16, "u16";
+        serialize_u32, u32, "u32";
+        serialize_u64, u64, "u64";
+        serialize_i128, i128, "i128";
+        serialize_u128, u128, "u128";
+        serialize_f32, f32, "f32";
+        serialize_f64, f64, "f64";
+        serialize_char, char, "char";
+        serialize_str, &str, "str";
+        serialize_bytes, &[u8], "bytes";
+    );
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("none"))
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        value.serialize(self)
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit variant"))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(PlRowSerdeError::unsupported_type("newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("seq"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        // elements go to `column_0`, `column_1`, ... unless named in the options
+        self.root
+            .struct_fields(self.options.dtype_conflict, "tuple")?;
+        Ok(PlRowSerFields::row(
+            &mut self.root,
+            &self.options,
+            column_name,
+        ))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        // the keys name the columns, which are the union of the keys of
+        // every row
+        self.root
+            .struct_fields(self.options.dtype_conflict, "map")?;
+        Ok(PlRowSerFields::row(
+            &mut self.root,
+            &self.options,
+            field_name,
+        ))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        // rows are appended to the columns built so far
+        self.root
+            .struct_fields(self.options.dtype_conflict, "struct")?;
+        Ok(PlRowSerFields::row(
+            &mut self.root,
+            &self.options,
+            field_name,
+        ))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("struct variant"))
     }
 }
 
-impl<'a> Serializer for PlRowSerStruct {
-    type Ok = PlRowSerStruct;
+macro_rules! impl_to_string {
+    ($($func: ident, $arg: ty;)*) => {
+        $(
+            fn $func(self, v: $arg) -> Result<Self::Ok, Self::Error> {
+                Ok(v.to_string())
+            }
+        ) *
+    };
+}
+
+/// Writes the key of an entry of a map row, which names its column.
+struct MapKeySer;
+
+impl Serializer for MapKeySer {
+    type Ok = String;
 
     type Error = crate::pl_row_error::PlRowSerdeError;
-    type SerializeSeq = PlSerPlaceHolder2;
 
-    type SerializeTuple = PlSerPlaceHolder2;
+    type SerializeSeq = Impossible<String, PlRowSerdeError>;
+
+    type SerializeTuple = Impossible<String, PlRowSerdeError>;
 
-    type SerializeTupleStruct = PlSerPlaceHolder2;
+    type SerializeTupleStruct = Impossible<String, PlRowSerdeError>;
 
-    type SerializeTupleVariant = PlSerPlaceHolder2;
+    type SerializeTupleVariant = Impossible<String, PlRowSerdeError>;
 
-    type SerializeMap = PlSerPlaceHolder2;
+    type SerializeMap = Impossible<String, PlRowSerdeError>;
 
-    type SerializeStruct = PlRowSerStruct;
+    type SerializeStruct = Impossible<String, PlRowSerdeError>;
 
-    type SerializeStructVariant = PlSerPlaceHolder2;
+    type SerializeStructVariant = Impossible<String, PlRowSerdeError>;
 
-    impl_as_todo!(
-        serialize_bool, bool;
+    impl_to_string!(
         serialize_i8, i8;
         serialize_i16, i16;
         serialize_i32, i32;
@@ -934,183 +1493,328 @@ impl<'a> Serializer for PlRowSerStruct {
         serialize_u16, u16;
         serialize_u32, u32;
         serialize_u64, u64;
-        serialize_f32, f32;
-        serialize_f64, f64;
+        serialize_i128, i128;
+        serialize_u128, u128;
         serialize_char, char;
         serialize_str, &str;
-        serialize_bytes, &[u8];
+    );
+
+    impl_as_unsupported!(
+        serialize_bool, bool, "bool";
+        serialize_f32, f32, "f32";
+        serialize_f64, f64, "f64";
+        serialize_bytes, &[u8], "bytes";
     );
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("none"))
     }
 
     fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        value.serialize(self)
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("unit"))
     }
 
     fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        Ok(name.to_string())
     }
 
     fn serialize_unit_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
+        _name: &'static str,
+        _variant_index: u32,
         variant: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        Ok(variant.to_string())
     }
 
     fn serialize_newtype_struct<T>(
         self,
-        name: &'static str,
+        _name: &'static str,
         value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        value.serialize(self)
     }
 
     fn serialize_newtype_variant<T>(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        value: &T,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
     ) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("newtype variant"))
     }
 
-    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        todo!()
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("seq"))
     }
 
-    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
-        todo!()
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("tuple"))
     }
 
     fn serialize_tuple_struct(
         self,
-        name: &'static str,
-        len: usize,
+        _name: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("tuple struct"))
     }
 
     fn serialize_tuple_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        len: usize,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("tuple variant"))
     }
 
-    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        todo!()
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("map"))
     }
 
     fn serialize_struct(
         self,
-        name: &'static str,
-        len: usize,
+        _name: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(PlRowSerStruct::default())
+        Err(PlRowSerdeError::unsupported_type("struct"))
     }
 
     fn serialize_struct_variant(
         self,
-        name: &'static str,
-        variant_index: u32,
-        variant: &'static str,
-        len: usize,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
-        todo!()
+        Err(PlRowSerdeError::unsupported_type("struct variant"))
     }
 }
 
-impl SerializeStruct for PlRowSerStruct {
-    type Ok = PlRowSerStruct;
+/// Integers a value is made of, see [`IntegerSer`].
+#[derive(Clone, Copy)]
+enum Integers {
+    /// An integer and the name of its type.
+    One(i128, &'static str),
+    Pair(i128, i128),
+}
 
-    type Error = crate::PlRowSerdeError;
+macro_rules! impl_integer {
+    ($($func: ident, $arg: ty;)*) => {
+        $(
+            fn $func(self, v: $arg) -> Result<Self::Ok, Self::Error> {
+                Ok(Integers::One(v.into(), stringify!($arg)))
+            }
+        ) *
+    };
+}
 
-    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+/// Takes the integers out of the values the `temporal` and `decimal` modules
+/// wrap in the newtypes above, which they write as an integer or a pair of
+/// them to serializers that aren't human readable.
+struct IntegerSer;
+
+impl Serializer for IntegerSer {
+    type Ok = Integers;
+
+    type Error = crate::pl_row_error::PlRowSerdeError;
+
+    type SerializeSeq = Impossible<Integers, PlRowSerdeError>;
+
+    type SerializeTuple = IntegerPair;
+
+    type SerializeTupleStruct = Impossible<Integers, PlRowSerdeError>;
+
+    type SerializeTupleVariant = Impossible<Integers, PlRowSerdeError>;
+
+    type SerializeMap = Impossible<Integers, PlRowSerdeError>;
+
+    type SerializeStruct = Impossible<Integers, PlRowSerdeError>;
+
+    type SerializeStructVariant = Impossible<Integers, PlRowSerdeError>;
+
+    impl_integer!(
+        serialize_i8, i8;
+        serialize_i16, i16;
+        serialize_i32, i32;
+        serialize_i64, i64;
+        serialize_u8, u8;
+        serialize_u16, u16;
+        serialize_u32, u32;
+        serialize_u64, u64;
+        serialize_i128, i128;
+    );
+
+    impl_as_unsupported!(
+        serialize_bool, bool, "bool";
+        serialize_f32, f32, "f32";
+        serialize_f64, f64, "f64";
+        serialize_char, char, "char";
+        serialize_str, &str, "str";
+        serialize_bytes, &[u8], "bytes";
+    );
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        match i128::try_from(v) {
+            Ok(v) => Ok(Integers::One(v, "u128")),
+            Err(_) => Err(PlRowSerdeError::unsupported_type("u128")),
+        }
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("none"))
+    }
+
+    fn serialize_some<T>(self, _value: &T) -> Result<Self::Ok, Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        self.pl_ser_map.entry(key).or_insert_with(|| PlRowSer {
-            pl_ser: Series::new_null(key, 0),
-        });
-        let ser = if let Some(opts) = self.pl_ser_map.remove(key) {
-            opts
-        } else {
-            PlRowSer {
-                pl_ser: Series::new_null(key, 0),
-            }
-        };
-        let pl_ser = value.serialize(ser).unwrap();
-        self.pl_ser_map.insert(key, PlRowSer { pl_ser });
-        Ok(())
+        Err(PlRowSerdeError::unsupported_type("some"))
     }
 
-    fn end(self) -> Result<Self::Ok, Self::Error> {
-        Ok(self)
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("unit variant"))
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        Err(PlRowSerdeError::unsupported_type("newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("seq"))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        match len {
+            2 => Ok(IntegerPair::default()),
+            _ => Err(PlRowSerdeError::unsupported_type("tuple")),
+        }
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(PlRowSerdeError::unsupported_type("struct variant"))
     }
 }
 
+/// The elements of a pair written to [`IntegerSer`].
 #[derive(Default)]
-pub struct PlRowSerMap {
-    pl_ser_map: HashMap<String, PlRowSer>,
-    curr_key: String,
+struct IntegerPair {
+    values: [i128; 2],
+    len: usize,
 }
-impl<'a> SerializeMap for PlRowSerMap {
-    type Ok = PlRowSerMap;
+
+impl SerializeTuple for IntegerPair {
+    type Ok = Integers;
 
     type Error = crate::PlRowSerdeError;
 
-    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
-        let ser = if let Some(opts) = self.pl_ser_map.remove(&self.curr_key) {
-            opts
-        } else {
-            PlRowSer {
-                pl_ser: Series::new_null(&self.curr_key, 0),
-            }
+        let (Integers::One(value, _), Some(slot)) =
+            (value.serialize(IntegerSer)?, self.values.get_mut(self.len))
+        else {
+            return Err(PlRowSerdeError::unsupported_type("tuple"));
         };
-        let pl_ser = value.serialize(ser).unwrap();
-        self.pl_ser_map
-            .insert(std::mem::take(&mut self.curr_key), PlRowSer { pl_ser });
-        Ok(())
-    }
-    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
-    where
-        T: ?Sized + Serialize,
-    {
-        self.curr_key = key.serialize(PlRowSer::default()).unwrap().to_string();
-        self.pl_ser_map
-            .entry(self.curr_key.to_string())
-            .or_insert_with(|| PlRowSer {
-                pl_ser: Series::new_null(&self.curr_key, 0),
-            });
+        *slot = value;
+        self.len += 1;
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        Ok(self)
+        match (self.len, self.values) {
+            (2, [first, second]) => Ok(Integers::Pair(first, second)),
+            _ => Err(PlRowSerdeError::unsupported_type("tuple")),
+        }
     }
 }
@@ -0,0 +1,194 @@
+//! Write chrono values to temporal columns.
+//!
+//! Fields marked with `#[serde(with = "polars_deser_row::temporal")]` are
+//! written to the column of their chrono type:
+//!
+//! | field                                     | column                           |
+//! |-------------------------------------------|----------------------------------|
+//! | `NaiveDate`                               | `Date`                           |
+//! | `NaiveDateTime`                           | `Datetime(unit, None)`           |
+//! | `DateTime<Utc>`, `DateTime<FixedOffset>`  | `Datetime(unit, "UTC")`          |
+//! | `NaiveTime`                               | `Time`                           |
+//! | `Duration`                                | `Duration(unit)`                 |
+//!
+//! `Option`s of these work too. Unmarked dates, times and datetimes are the
+//! ISO 8601 text chrono formats them as, in String columns. Datetimes are written in the unit of
+//! [`crate::SerializeOptions::with_datetime_unit`] and durations in the one of
+//! [`crate::SerializeOptions::with_duration_unit`], both microseconds by
+//! default. Offsets aren't kept: zoned values are written in UTC like polars
+//! stores them, so a `DateTime<FixedOffset>` reads back at offset `+00:00`,
+//! the same instant.
+//!
+//! Only `Duration` needs the attribute to be read. Cells of Date, Datetime and
+//! Time columns are handed to chrono as strings, in the time unit and time
+//! zone of the column. Human readable serializers other than this crate's see
+//! the plain chrono value, the others the days of a date or the nanoseconds
+//! of a time or datetime, and all of them durations as nanoseconds.
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Subscription {
+//!     #[serde(with = "polars_deser_row::temporal")]
+//!     since: chrono::NaiveDate,
+//!     #[serde(with = "polars_deser_row::temporal")]
+//!     cancelled: Option<chrono::DateTime<chrono::Utc>>,
+//! }
+//! ```
+use std::fmt;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc,
+};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer, Serializer,
+};
+
+use crate::{
+    ser_root::{DATE, DATETIME, DATETIME_UTC, DURATION, TIME},
+    wrapped::{Physical, Wrapped},
+};
+
+/// Values written to a temporal column, see the [module docs](self).
+pub use crate::wrapped::Wrapped as Temporal;
+pub use crate::wrapped::{deserialize, serialize};
+
+/// `num_days_from_ce` of 1970-01-01.
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// Days since the epoch.
+impl Physical for NaiveDate {
+    const NAME: &'static str = DATE;
+
+    type Repr = i32;
+
+    fn to_physical(&self) -> i32 {
+        self.num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    fn from_physical(days: i32) -> Option<Self> {
+        NaiveDate::from_num_days_from_ce_opt(days.checked_add(EPOCH_DAYS_FROM_CE)?)
+    }
+}
+
+/// Nanoseconds since midnight.
+impl Physical for NaiveTime {
+    const NAME: &'static str = TIME;
+
+    type Repr = i64;
+
+    fn to_physical(&self) -> i64 {
+        i64::from(self.num_seconds_from_midnight()) * NANOS_PER_SEC + i64::from(self.nanosecond())
+    }
+
+    fn from_physical(nanos: i64) -> Option<Self> {
+        let secs = u32::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32)
+    }
+}
+
+/// Nanoseconds since the epoch.
+impl Physical for DateTime<Utc> {
+    const NAME: &'static str = DATETIME_UTC;
+
+    type Repr = i128;
+
+    fn to_physical(&self) -> i128 {
+        i128::from(self.timestamp()) * i128::from(NANOS_PER_SEC)
+            + i128::from(self.timestamp_subsec_nanos())
+    }
+
+    fn from_physical(nanos: i128) -> Option<Self> {
+        let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC.into())).ok()?;
+        DateTime::from_timestamp(secs, nanos.rem_euclid(NANOS_PER_SEC.into()) as u32)
+    }
+}
+
+/// Nanoseconds since the epoch, read back at offset `+00:00`.
+impl Physical for DateTime<FixedOffset> {
+    const NAME: &'static str = DATETIME_UTC;
+
+    type Repr = i128;
+
+    fn to_physical(&self) -> i128 {
+        self.with_timezone(&Utc).to_physical()
+    }
+
+    fn from_physical(nanos: i128) -> Option<Self> {
+        let utc = DateTime::<Utc>::from_physical(nanos)?;
+        Some(utc.with_timezone(&FixedOffset::east_opt(0)?))
+    }
+}
+
+/// Nanoseconds since the epoch.
+impl Physical for NaiveDateTime {
+    const NAME: &'static str = DATETIME;
+
+    type Repr = i128;
+
+    fn to_physical(&self) -> i128 {
+        self.and_utc().to_physical()
+    }
+
+    fn from_physical(nanos: i128) -> Option<Self> {
+        DateTime::<Utc>::from_physical(nanos).map(|i| i.naive_utc())
+    }
+}
+
+/// chrono has no serde support for durations, they are nanoseconds here.
+impl Wrapped for Duration {
+    fn serialize_wrapped<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.num_nanoseconds() {
+            Some(nanos) => serializer.serialize_newtype_struct(DURATION, &nanos),
+            None => Err(serde::ser::Error::custom(format!(
+                "duration {self} out of the range of i64 nanoseconds"
+            ))),
+        }
+    }
+
+    fn deserialize_wrapped<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(DURATION, NanosVisitor)
+    }
+}
+
+struct NanosVisitor;
+
+impl<'de> Visitor<'de> for NanosVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("nanoseconds")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Duration::nanoseconds(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Duration::nanoseconds)
+    }
+}
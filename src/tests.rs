@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
 use std::io::Cursor;
 
@@ -8,7 +9,7 @@ use polars::prelude::LiteralValue;
 use polars::{
     df,
     frame::DataFrame,
-    prelude::{IntoLazy, NamedFrom},
+    prelude::{IntoLazy, IntoSeries, NamedFrom},
     series::Series,
 };
 
@@ -200,6 +201,54 @@ fn deser_primitive_float() {
     )
 }
 
+#[test]
+fn deser_borrowed_str_and_bytes() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Borrowed<'a> {
+        utf8: &'a str,
+        bytes: &'a [u8],
+        #[serde(borrow)]
+        cow: Cow<'a, str>,
+    }
+
+    let df = df!(
+        "utf8" => ["hello", "a string long enough to not be inlined"],
+        "bytes" => ["hello".as_bytes(), "world".as_bytes()],
+        "cow" => ["lol", "wow"],
+    )
+    .unwrap();
+
+    let ty_stack = crate::deserialize_all_borrowed::<Borrowed>(&df)
+        .into_iter()
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ty_stack,
+        vec![
+            Borrowed {
+                utf8: "hello",
+                bytes: b"hello",
+                cow: Cow::Borrowed("lol"),
+            },
+            Borrowed {
+                utf8: "a string long enough to not be inlined",
+                bytes: b"world",
+                cow: Cow::Borrowed("wow"),
+            },
+        ]
+    );
+    // no copies were made
+    assert!(matches!(ty_stack[0].cow, Cow::Borrowed(_)));
+    assert_eq!(
+        ty_stack[1].utf8.as_ptr(),
+        df["utf8"].str().unwrap().get(1).unwrap().as_ptr()
+    );
+
+    let ty: Borrowed = crate::deserialize_single_row_borrowed(&df, 0).unwrap();
+    assert_eq!(ty.utf8, "hello");
+}
+
 #[test]
 fn deser_seq() {
     macro_rules! template {
@@ -363,19 +412,21 @@ fn testasdf() {
         }
         .unwrap()
         .lazy()
-        .with_column(
-            pl::col("cat").cast(polars::datatypes::DataType::Categorical(
+        .with_columns(["cat", "cat_str", "cat_bytes"].map(|name| {
+            pl::col(name).cast(polars::datatypes::DataType::Categorical(
                 None,
                 polars::datatypes::CategoricalOrdering::Lexical,
-            )),
-        )
+            ))
+        }))
         .collect()
         .unwrap();
 
-        println!("{df}");
-        let asdf = Asdf::deserialize(SeriesDeser::new(df, 0)).unwrap();
-        println!("{asdf:#?}");
+        let asdf = Asdf::deserialize(SeriesDeser::new(&df, 0)).unwrap();
+        assert_eq!(asdf.cat_str, "Lol");
+        assert_eq!(&*asdf.cat_bytes, b"Lol");
+        assert_eq!(asdf.char_, 'c');
     }
+    main();
 
     #[derive(serde::Deserialize, Debug)]
     struct Asdf {
@@ -393,6 +444,7 @@ fn testasdf() {
         utf8: String,
         bytes_box: Box<[u8]>,
         bytes_vec: Vec<u8>,
+        #[serde(rename = "char")]
         char_: char,
         cat: AsdfEnum,
         cat_str: String,
@@ -406,3 +458,2642 @@ fn testasdf() {
         Things,
     }
 }
+
+#[test]
+fn deser_any_self_describing() {
+    use std::collections::HashMap;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(untagged)]
+    enum Cell {
+        Null,
+        Int(i64),
+        Float(f64),
+        Text(String),
+        List(Vec<Cell>),
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        id: i64,
+        #[serde(flatten)]
+        rest: HashMap<String, Cell>,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(tag = "kind")]
+    enum Event {
+        Click { x: i32 },
+        Idle,
+    }
+
+    let df = df!(
+        "id" => [1i64, 2],
+        "score" => [Some(1.5f64), None],
+        "name" => ["a", "b"],
+        "tags" => [Series::new("", [1i32, 2]), Series::new("", [3i32])],
+    )
+    .unwrap();
+
+    let ty: Row = crate::deserialize_single_row(df.clone(), 0).unwrap();
+    assert_eq!(
+        ty,
+        Row {
+            id: 1,
+            rest: HashMap::from([
+                ("score".to_string(), Cell::Float(1.5)),
+                ("name".to_string(), Cell::Text("a".to_string())),
+                (
+                    "tags".to_string(),
+                    Cell::List(vec![Cell::Int(1), Cell::Int(2)])
+                ),
+            ]),
+        }
+    );
+    let ty: Row = crate::deserialize_single_row(df.clone(), 1).unwrap();
+    assert_eq!(ty.rest["score"], Cell::Null);
+    assert_eq!(ty.rest["tags"], Cell::List(vec![Cell::Int(3)]));
+
+    let df = df!(
+        "kind" => ["Click", "Idle"],
+        "x" => [Some(1i32), None],
+    )
+    .unwrap();
+    let ty_stack = crate::deserialize_all::<Event>(df)
+        .into_iter()
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(ty_stack, vec![Event::Click { x: 1 }, Event::Idle]);
+
+    // newtype structs read their inner value, unit structs any cell
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Id(i64);
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Name(String);
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Marker;
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Keyed {
+        id: Id,
+        name: Name,
+        marker: Marker,
+    }
+
+    let df = df!(
+        "id" => [1i64, 2],
+        "name" => ["a", "b"],
+        "marker" => ["Marker", "Marker"],
+    )
+    .unwrap();
+    let ty_stack = crate::deserialize_all::<Keyed>(df.clone())
+        .into_iter()
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ty_stack,
+        vec![
+            Keyed {
+                id: Id(1),
+                name: Name("a".into()),
+                marker: Marker,
+            },
+            Keyed {
+                id: Id(2),
+                name: Name("b".into()),
+                marker: Marker,
+            },
+        ]
+    );
+    let ty: Keyed = crate::deserialize_single_row(df, 1).unwrap();
+    assert_eq!(ty.id, Id(2));
+}
+
+#[test]
+fn deser_projection_by_fields() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Narrow {
+        int64: i64,
+        utf8: String,
+        // no such column
+        missing: Option<i32>,
+        #[serde(default)]
+        defaulted: u8,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Required {
+        int64: i64,
+        missing: i32,
+    }
+
+    // the unread columns have types `Narrow` can't read at all
+    let df = df!(
+        "unread_bool" => [true, false],
+        "utf8" => ["a", "b"],
+        "unread_list" => [Series::new("", [1i8]), Series::new("", [2i8])],
+        "int64" => [1i64, 2],
+    )
+    .unwrap();
+
+    let ty_stack = crate::deserialize_all::<Narrow>(df.clone())
+        .into_iter()
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ty_stack,
+        vec![
+            Narrow {
+                int64: 1,
+                utf8: "a".to_string(),
+                missing: None,
+                defaulted: 0,
+            },
+            Narrow {
+                int64: 2,
+                utf8: "b".to_string(),
+                missing: None,
+                defaulted: 0,
+            },
+        ]
+    );
+
+    let res = crate::deserialize_single_row::<Required>(df, 0);
+    assert!(res
+        .unwrap_err()
+        .to_string()
+        .contains("missing field `missing`"));
+}
+
+#[test]
+fn deser_row_cursor_across_chunks() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Row<'a> {
+        int64: i64,
+        float32: Option<f32>,
+        utf8: &'a str,
+        // not one of the typed columns
+        list: Vec<u8>,
+    }
+
+    let first = df!(
+        "int64" => [1i64, 2],
+        "float32" => [Some(1f32), None],
+        "utf8" => ["a", "b"],
+        "list" => [Series::new("", [1u8]), Series::new("", [2u8, 2])],
+    )
+    .unwrap();
+    let second = df!(
+        "int64" => [3i64],
+        "float32" => [Some(3f32)],
+        "utf8" => ["c"],
+        "list" => [Series::new("", [3u8])],
+    )
+    .unwrap();
+    let df = first.vstack(&second).unwrap();
+    assert_eq!(df["int64"].n_chunks(), 2);
+
+    let ty_stack = crate::iter_rows::<Row>(&df)
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ty_stack,
+        vec![
+            Row {
+                int64: 1,
+                float32: Some(1.),
+                utf8: "a",
+                list: vec![1],
+            },
+            Row {
+                int64: 2,
+                float32: None,
+                utf8: "b",
+                list: vec![2, 2],
+            },
+            Row {
+                int64: 3,
+                float32: Some(3.),
+                utf8: "c",
+                list: vec![3],
+            },
+        ]
+    );
+
+    // the cursor can also move backwards
+    let mut cursor = crate::RowCursor::<Row>::new(&df);
+    assert_eq!(cursor.row(2).unwrap().int64, 3);
+    assert_eq!(cursor.row(0).unwrap().int64, 1);
+    assert!(cursor.row(3).is_err());
+}
+
+#[test]
+fn deser_nested_struct_rows() {
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Leaf {
+        value: i32,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Inner {
+        int8: i8,
+        leaf: Leaf,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Item {
+        int16: i16,
+        label: String,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Outer {
+        id: i64,
+        inner: Inner,
+        maybe_inner: Option<Item>,
+        items: Vec<Item>,
+    }
+
+    let leaf = StructChunked::new("leaf", &[Series::new("value", [10i32, 20, 30])])
+        .unwrap()
+        .into_series();
+    let inner = StructChunked::new("inner", &[Series::new("int8", [1i8, 2, 3]), leaf])
+        .unwrap()
+        .into_series();
+    let maybe_inner = StructChunked::new(
+        "maybe_inner",
+        &[
+            Series::new("int16", [Some(1i16), None, Some(3)]),
+            Series::new("label", [Some("a"), None, Some("c")]),
+        ],
+    )
+    .unwrap()
+    .into_series();
+    let item = |int16: &[i16], label: &[&str]| {
+        StructChunked::new(
+            "",
+            &[Series::new("int16", int16), Series::new("label", label)],
+        )
+        .unwrap()
+        .into_series()
+    };
+    let items = Series::new(
+        "items",
+        [
+            item(&[1], &["x"]),
+            item(&[], &[]),
+            item(&[2, 3], &["y", "z"]),
+        ],
+    );
+    let df = DataFrame::new(vec![
+        Series::new("id", [1i64, 2, 3]),
+        inner,
+        maybe_inner,
+        items,
+    ])
+    .unwrap();
+
+    let ty_stack = crate::deserialize_all::<Outer>(df.clone())
+        .into_iter()
+        .map(|i| i.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ty_stack,
+        vec![
+            Outer {
+                id: 1,
+                inner: Inner {
+                    int8: 1,
+                    leaf: Leaf { value: 10 },
+                },
+                maybe_inner: Some(Item {
+                    int16: 1,
+                    label: "a".to_string(),
+                }),
+                items: vec![Item {
+                    int16: 1,
+                    label: "x".to_string(),
+                }],
+            },
+            Outer {
+                id: 2,
+                inner: Inner {
+                    int8: 2,
+                    leaf: Leaf { value: 20 },
+                },
+                maybe_inner: None,
+                items: vec![],
+            },
+            Outer {
+                id: 3,
+                inner: Inner {
+                    int8: 3,
+                    leaf: Leaf { value: 30 },
+                },
+                maybe_inner: Some(Item {
+                    int16: 3,
+                    label: "c".to_string(),
+                }),
+                items: vec![
+                    Item {
+                        int16: 2,
+                        label: "y".to_string(),
+                    },
+                    Item {
+                        int16: 3,
+                        label: "z".to_string(),
+                    },
+                ],
+            },
+        ]
+    );
+
+    // the single row path agrees with the cursor
+    let ty: Outer = crate::deserialize_single_row(df, 2).unwrap();
+    assert_eq!(ty, ty_stack.into_iter().nth(2).unwrap());
+}
+
+#[test]
+fn deser_enum_layouts() {
+    use polars::datatypes::DataType;
+
+    fn read_all<T: serde::de::DeserializeOwned>(df: &DataFrame) -> Vec<T> {
+        crate::deserialize_all_borrowed(df)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    enum Payload {
+        Click { x: i32, y: i32 },
+        Scroll(f32),
+        Idle,
+    }
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    enum Color {
+        Red,
+        Green,
+    }
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Event {
+        payload: Payload,
+        color: Color,
+        category: Color,
+        maybe: Option<Payload>,
+    }
+
+    // externally tagged: a field per variant, the one set in the row wins
+    let payload = |name: &str| {
+        let click = StructChunked::new(
+            "Click",
+            &[
+                Series::new("x", [Some(1i32), None, None]),
+                Series::new("y", [Some(2i32), None, None]),
+            ],
+        )
+        .unwrap()
+        .into_series();
+        StructChunked::new(
+            name,
+            &[
+                click,
+                Series::new("Scroll", [None, Some(0.5f32), None]),
+                Series::new("Idle", [None, None, Some(true)]),
+            ],
+        )
+        .unwrap()
+        .into_series()
+    };
+    let category = Series::new("category", ["Green", "Red", "Green"])
+        .cast(&DataType::Categorical(None, Default::default()))
+        .unwrap();
+    let maybe = StructChunked::new(
+        "maybe",
+        &[
+            Series::new("Scroll", [Some(1.5f32), None, None]),
+            Series::new("Idle", [None, None, Some(true)]),
+        ],
+    )
+    .unwrap()
+    .into_series();
+    let df = DataFrame::new(vec![
+        payload("payload"),
+        Series::new("color", ["Red", "Green", "Red"]),
+        category,
+        maybe,
+    ])
+    .unwrap();
+    assert_eq!(
+        read_all::<Event>(&df),
+        [
+            Event {
+                payload: Payload::Click { x: 1, y: 2 },
+                color: Color::Red,
+                category: Color::Green,
+                maybe: Some(Payload::Scroll(1.5)),
+            },
+            Event {
+                payload: Payload::Scroll(0.5),
+                color: Color::Green,
+                category: Color::Red,
+                maybe: None,
+            },
+            Event {
+                payload: Payload::Idle,
+                color: Color::Red,
+                category: Color::Green,
+                maybe: Some(Payload::Idle),
+            },
+        ]
+    );
+
+    // internally tagged
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(tag = "type")]
+    enum Internal {
+        Click { x: i32, y: i32 },
+        Idle,
+    }
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct InternalRow {
+        payload: Internal,
+    }
+    let df = DataFrame::new(vec![StructChunked::new(
+        "payload",
+        &[
+            Series::new("type", ["Click", "Idle"]),
+            Series::new("x", [Some(1i32), None]),
+            Series::new("y", [Some(2i32), None]),
+        ],
+    )
+    .unwrap()
+    .into_series()])
+    .unwrap();
+    assert_eq!(
+        read_all::<InternalRow>(&df),
+        [
+            InternalRow {
+                payload: Internal::Click { x: 1, y: 2 }
+            },
+            InternalRow {
+                payload: Internal::Idle
+            },
+        ]
+    );
+
+    // adjacently tagged
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(tag = "t", content = "c")]
+    enum Adjacent {
+        Click { x: i32, y: i32 },
+        Drag { x: i32, y: i32 },
+    }
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct AdjacentRow {
+        payload: Adjacent,
+    }
+    let content = StructChunked::new(
+        "c",
+        &[Series::new("x", [1i32, 3]), Series::new("y", [2i32, 4])],
+    )
+    .unwrap()
+    .into_series();
+    let df = DataFrame::new(vec![StructChunked::new(
+        "payload",
+        &[Series::new("t", ["Click", "Drag"]), content],
+    )
+    .unwrap()
+    .into_series()])
+    .unwrap();
+    assert_eq!(
+        read_all::<AdjacentRow>(&df),
+        [
+            AdjacentRow {
+                payload: Adjacent::Click { x: 1, y: 2 }
+            },
+            AdjacentRow {
+                payload: Adjacent::Drag { x: 3, y: 4 }
+            },
+        ]
+    );
+
+    // untagged
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    #[serde(untagged)]
+    enum Shape {
+        Circle { r: f64 },
+        Rect { w: f64, h: f64 },
+    }
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct ShapeRow {
+        shape: Shape,
+    }
+    let df = DataFrame::new(vec![StructChunked::new(
+        "shape",
+        &[
+            Series::new("r", [Some(1.0f64), None]),
+            Series::new("w", [None, Some(2.0f64)]),
+            Series::new("h", [None, Some(3.0f64)]),
+        ],
+    )
+    .unwrap()
+    .into_series()])
+    .unwrap();
+    assert_eq!(
+        read_all::<ShapeRow>(&df),
+        [
+            ShapeRow {
+                shape: Shape::Circle { r: 1.0 }
+            },
+            ShapeRow {
+                shape: Shape::Rect { w: 2.0, h: 3.0 }
+            },
+        ]
+    );
+}
+
+#[test]
+fn deser_enum_single_struct_field() {
+    use crate::{EnumLayout, SerializeOptions};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    // the internal layout writes the tag next to one struct field, the shape
+    // of the adjacent layout
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    enum Gesture {
+        Tap { at: Point },
+        Rest,
+    }
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        gesture: Gesture,
+    }
+
+    let rows = vec![
+        Row {
+            gesture: Gesture::Tap {
+                at: Point { x: 1, y: 2 },
+            },
+        },
+        Row {
+            gesture: Gesture::Rest,
+        },
+    ];
+    let layouts = [
+        EnumLayout::default(),
+        EnumLayout::Internal { tag: "kind" },
+        EnumLayout::Adjacent {
+            tag: "kind",
+            content: "data",
+        },
+    ];
+    for layout in layouts {
+        let options = SerializeOptions::new().with_enum_layout(layout);
+        let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+        let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(back, rows, "{layout:?}");
+    }
+}
+
+#[test]
+fn ser_enum_layouts_round_trip() {
+    use crate::{EnumLayout, SerializeOptions};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    enum Payload {
+        Click { x: i32, y: i32 },
+        Drag { to: Point },
+        Scroll(f32),
+        Pair(i32, String),
+        Idle,
+    }
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Event {
+        id: i64,
+        payload: Payload,
+    }
+
+    let events = vec![
+        Event {
+            id: 0,
+            payload: Payload::Idle,
+        },
+        Event {
+            id: 1,
+            payload: Payload::Click { x: 1, y: 2 },
+        },
+        Event {
+            id: 2,
+            payload: Payload::Scroll(0.5),
+        },
+        Event {
+            id: 3,
+            payload: Payload::Pair(4, "four".to_string()),
+        },
+        Event {
+            id: 4,
+            payload: Payload::Idle,
+        },
+        Event {
+            id: 5,
+            payload: Payload::Drag {
+                to: Point { x: 5, y: 6 },
+            },
+        },
+    ];
+    let layouts = [
+        (
+            EnumLayout::default(),
+            ["variant", "Click", "Scroll", "Pair", "Drag"].as_slice(),
+        ),
+        (
+            EnumLayout::Internal { tag: "type" },
+            ["type", "x", "y", "Scroll", "Pair", "to"].as_slice(),
+        ),
+        (
+            EnumLayout::Adjacent {
+                tag: "t",
+                content: "c",
+            },
+            ["t", "c"].as_slice(),
+        ),
+    ];
+    for (layout, fields) in layouts {
+        let options = SerializeOptions::new().with_enum_layout(layout);
+        let df = crate::serialize_into_dataframe_with_options(events.iter(), options).unwrap();
+        let payload = df["payload"].struct_().unwrap();
+        let names: Vec<_> = payload.fields().iter().map(|i| i.name()).collect();
+        assert_eq!(names, fields, "{layout:?}");
+
+        let back: Vec<Event> = crate::deserialize_all_borrowed(&df)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(back, events, "{layout:?}");
+    }
+}
+
+#[test]
+fn ser_nested_struct_round_trip() {
+    use polars::datatypes::{DataType, Field};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Leaf {
+        label: String,
+        score: Option<f64>,
+    }
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Inner {
+        value: i32,
+        leaf: Leaf,
+    }
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Outer {
+        id: i64,
+        inner: Inner,
+        maybe_inner: Option<Inner>,
+    }
+
+    let inner = |value: i32| Inner {
+        value,
+        leaf: Leaf {
+            label: value.to_string(),
+            score: Some(value as f64),
+        },
+    };
+    let rows = vec![
+        Outer {
+            id: 0,
+            inner: inner(0),
+            maybe_inner: None,
+        },
+        Outer {
+            id: 1,
+            inner: inner(1),
+            maybe_inner: Some(inner(10)),
+        },
+        Outer {
+            id: 2,
+            inner: inner(2),
+            maybe_inner: None,
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    let leaf = DataType::Struct(vec![
+        Field::new("label", DataType::String),
+        Field::new("score", DataType::Float64),
+    ]);
+    let inner_dtype = DataType::Struct(vec![
+        Field::new("value", DataType::Int32),
+        Field::new("leaf", leaf),
+    ]);
+    assert_eq!(df["inner"].dtype(), &inner_dtype);
+    assert_eq!(df["maybe_inner"].dtype(), &inner_dtype);
+    assert_eq!(df.height(), 3);
+
+    let back: Vec<Outer> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[test]
+fn ser_null_alignment() {
+    use polars::prelude::AnyValue;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        id: u32,
+        flips: Option<i64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        skipped: Option<String>,
+        list: Option<Vec<u16>>,
+    }
+
+    let rows = vec![
+        Row {
+            id: 0,
+            flips: None,
+            skipped: None,
+            list: None,
+        },
+        Row {
+            id: 1,
+            flips: Some(1),
+            skipped: Some("late".into()),
+            list: Some(vec![]),
+        },
+        Row {
+            id: 2,
+            flips: None,
+            skipped: None,
+            list: None,
+        },
+        Row {
+            id: 3,
+            flips: Some(3),
+            skipped: None,
+            list: Some(vec![1, 2]),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df.height(), 4);
+    for column in df.get_columns() {
+        assert_eq!(column.len(), 4, "{}", column.name());
+    }
+    assert_eq!(df["flips"].null_count(), 2);
+    // first written at row 1, then skipped
+    assert_eq!(df["skipped"].null_count(), 3);
+    assert_eq!(df["skipped"].get(1).unwrap(), AnyValue::String("late"));
+    assert_eq!(df["list"].null_count(), 2);
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[test]
+fn ser_struct_nulls() {
+    use std::collections::HashMap;
+
+    use serde::de::IgnoredAny;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Inner {
+        a: Option<i32>,
+        b: Option<String>,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Empty {}
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        inner: Option<Inner>,
+        empty: Empty,
+        maybe_empty: Option<Empty>,
+    }
+
+    let rows = vec![
+        Row {
+            inner: Some(Inner {
+                a: Some(1),
+                b: None,
+            }),
+            empty: Empty {},
+            maybe_empty: None,
+        },
+        Row {
+            inner: Some(Inner { a: None, b: None }),
+            empty: Empty {},
+            maybe_empty: Some(Empty {}),
+        },
+        Row {
+            inner: None,
+            empty: Empty {},
+            maybe_empty: None,
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df.height(), 3);
+    for column in df.get_columns() {
+        assert_eq!(column.len(), 3, "{}", column.name());
+    }
+
+    // structs have no validity of their own, the rows of null fields that
+    // aren't null are told apart by a last validity field
+    let names = |column: &str| -> Vec<String> {
+        let fields = df[column].struct_().unwrap().fields();
+        fields.iter().map(|i| i.name().to_string()).collect()
+    };
+    assert_eq!(names("inner"), ["a", "b", "$polars_deser_row::validity"]);
+    assert_eq!(names("maybe_empty"), ["$polars_deser_row::validity"]);
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // what reads them as a map doesn't see the validity field
+    let maps: Vec<HashMap<String, Option<HashMap<String, IgnoredAny>>>> =
+        crate::deserialize_all_borrowed(&df.select(["inner"]).unwrap())
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+    assert_eq!(maps[1]["inner"].as_ref().map(HashMap::len), Some(2));
+    assert!(maps[2]["inner"].is_none());
+
+    // without such rows the fields are the struct's own
+    let df = crate::serialize_into_dataframe(rows[..1].iter()).unwrap();
+    let fields = df["inner"].struct_().unwrap().fields();
+    let names: Vec<_> = fields.iter().map(|i| i.name()).collect();
+    assert_eq!(names, ["a", "b"]);
+}
+
+#[test]
+fn deser_error_variants() {
+    use crate::PlRowSerdeError;
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Deserialize, Debug)]
+    struct Inner {
+        #[allow(dead_code)]
+        value: i32,
+    }
+    #[derive(serde::Deserialize, Debug)]
+    struct Row {
+        #[allow(dead_code)]
+        int64: i64,
+    }
+    #[derive(serde::Deserialize, Debug)]
+    struct Nested {
+        #[allow(dead_code)]
+        inner: Inner,
+    }
+    #[derive(serde::Deserialize, Debug)]
+    struct Absent {
+        #[allow(dead_code)]
+        absent: i32,
+    }
+
+    let df = df!(
+        "int64" => [Some(1i64), None],
+        "utf8" => ["a", "b"],
+    )
+    .unwrap();
+
+    let err = crate::deserialize_all_borrowed::<Row>(&df)
+        .into_iter()
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::UnexpectedNull {
+            column: Some("int64".to_string()),
+            row: Some(1),
+            expected: "i64",
+            found: DataType::Int64,
+        }
+    );
+
+    let err = crate::deserialize_single_row_borrowed::<Absent>(&df, 0).unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::MissingColumn {
+            column: "absent".to_string(),
+            row: Some(0),
+        }
+    );
+
+    let err = crate::deserialize_single_row_borrowed::<Row>(&df, 5).unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::RowOutOfRange {
+            column: None,
+            row: 5,
+            height: 2,
+        }
+    );
+
+    // the column is the path through the struct columns
+    let inner = StructChunked::new("inner", &[Series::new("value", ["x", "y"])]).unwrap();
+    let df = DataFrame::new(vec![inner.into_series()]).unwrap();
+    let err = crate::deserialize_all_borrowed::<Nested>(&df)
+        .into_iter()
+        .nth(1)
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::TypeMismatch {
+            column: Some("inner.value".to_string()),
+            row: Some(1),
+            expected: "i32",
+            found: DataType::String,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "column `inner.value`, row 1: expected i32, found str"
+    );
+}
+
+#[test]
+fn ser_error_type_mismatch() {
+    use crate::PlRowSerdeError;
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Serialize)]
+    #[serde(untagged)]
+    enum Value {
+        Small(i32),
+        Large(i64),
+    }
+    #[derive(serde::Serialize)]
+    struct Row {
+        value: Value,
+    }
+
+    let rows = [
+        Row {
+            value: Value::Small(1),
+        },
+        Row {
+            value: Value::Large(2),
+        },
+    ];
+    let err = crate::serialize_into_dataframe(rows.into_iter()).unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::TypeMismatch {
+            column: Some("value".to_string()),
+            row: Some(1),
+            expected: "i64",
+            found: DataType::Int32,
+        }
+    );
+}
+
+#[test]
+fn ser_dtype_conflicts() {
+    use crate::{DtypeConflict, SerializeOptions};
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Serialize)]
+    #[serde(untagged)]
+    enum Value {
+        Small(i32),
+        Large(i64),
+        Text(&'static str),
+    }
+    #[derive(serde::Serialize)]
+    struct Row {
+        id: u8,
+        value: Option<Value>,
+    }
+
+    let serialize = |values: Vec<Option<Value>>, policy| {
+        let rows = values.into_iter().enumerate().map(|(id, value)| Row {
+            id: id as u8,
+            value,
+        });
+        let options = SerializeOptions::new().with_dtype_conflict(policy);
+        crate::serialize_into_dataframe_with_options(rows, options)
+    };
+
+    let err = serialize(
+        vec![Some(Value::Small(1)), None, Some(Value::Large(2))],
+        DtypeConflict::Error,
+    )
+    .unwrap_err();
+    assert_eq!(err.column(), Some("value"));
+    assert_eq!(err.row(), Some(2));
+
+    let df = serialize(
+        vec![
+            Some(Value::Small(1)),
+            None,
+            Some(Value::Large(2)),
+            Some(Value::Small(3)),
+        ],
+        DtypeConflict::Supertype,
+    )
+    .unwrap();
+    assert_eq!(df["value"].dtype(), &DataType::Int64);
+    assert_eq!(
+        df["value"],
+        Series::new("value", [Some(1i64), None, Some(2), Some(3)])
+    );
+    assert_eq!(df["id"].len(), 4);
+
+    let df = serialize(
+        vec![Some(Value::Small(1)), Some(Value::Text("a")), None],
+        DtypeConflict::String,
+    )
+    .unwrap();
+    assert_eq!(
+        df["value"],
+        Series::new("value", [Some("1"), Some("a"), None])
+    );
+}
+
+#[test]
+fn ser_column_order_and_layout() {
+    use crate::SerializeOptions;
+
+    #[derive(serde::Serialize)]
+    struct Row {
+        user_id: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        display_name: Option<&'static str>,
+        score: f64,
+        internal_note: &'static str,
+    }
+
+    let rows = || {
+        [
+            Row {
+                user_id: 1,
+                display_name: None,
+                score: 0.5,
+                internal_note: "a",
+            },
+            Row {
+                user_id: 2,
+                display_name: Some("two"),
+                score: 1.5,
+                internal_note: "b",
+            },
+        ]
+        .into_iter()
+    };
+
+    // declaration order, even for a field the first row skips
+    let df = crate::serialize_into_dataframe(rows()).unwrap();
+    assert_eq!(
+        df.get_column_names(),
+        ["user_id", "display_name", "score", "internal_note"]
+    );
+
+    let camel_case = |name: &str| {
+        let mut words = name.split('_');
+        let mut out = words.next().unwrap_or_default().to_string();
+        for word in words {
+            let mut chars = word.chars();
+            out.extend(chars.next().map(|i| i.to_ascii_uppercase()));
+            out.push_str(chars.as_str());
+        }
+        out
+    };
+    let options = SerializeOptions::new()
+        .with_exclude(["internal_note"])
+        .with_first(["score"])
+        .with_rename(camel_case);
+    let df = crate::serialize_into_dataframe_with_options(rows(), options).unwrap();
+    assert_eq!(df.get_column_names(), ["score", "userId", "displayName"]);
+
+    let options = SerializeOptions::new().with_include(["score", "user_id"]);
+    let df = crate::serialize_into_dataframe_with_options(rows(), options).unwrap();
+    assert_eq!(df.get_column_names(), ["user_id", "score"]);
+    assert_eq!(df.height(), 2);
+}
+
+#[test]
+fn ser_maps() {
+    use std::collections::{BTreeMap, HashMap};
+
+    use polars::datatypes::{DataType, Field};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        id: u8,
+        counts: HashMap<String, i32>,
+        labels: Option<BTreeMap<u16, Option<String>>>,
+    }
+
+    let rows = vec![
+        Row {
+            id: 0,
+            counts: HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+            labels: None,
+        },
+        Row {
+            id: 1,
+            counts: HashMap::new(),
+            labels: Some(BTreeMap::from([(7, Some("seven".to_string())), (8, None)])),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(
+        df["counts"].dtype(),
+        &DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("key", DataType::String),
+            Field::new("value", DataType::Int32),
+        ])))
+    );
+    assert_eq!(df["labels"].null_count(), 1);
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // no entries in any row leave nothing to type the entries with
+    let rows = vec![
+        Row {
+            id: 0,
+            counts: HashMap::new(),
+            labels: Some(BTreeMap::new()),
+        },
+        Row {
+            id: 1,
+            counts: HashMap::new(),
+            labels: None,
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // map rows: the columns are the union of the keys
+    let rows = vec![
+        BTreeMap::from([("x", 1i64), ("y", 2)]),
+        BTreeMap::from([("y", 3), ("z", 4)]),
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df.get_column_names(), ["x", "y", "z"]);
+    assert_eq!(df["x"], Series::new("x", [Some(1i64), None]));
+    assert_eq!(df["y"], Series::new("y", [2i64, 3]));
+    assert_eq!(df["z"], Series::new("z", [None, Some(4i64)]));
+}
+
+#[test]
+fn ser_tuple_fields_round_trip() {
+    use polars::datatypes::{DataType, Field};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Point(f64, f64);
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        bbox: (f32, f32, f32, f32),
+        pair: (String, u32),
+        span: Option<(i64, i64)>,
+        point: Point,
+    }
+
+    let rows = vec![
+        Row {
+            bbox: (0., 0., 1., 1.),
+            pair: ("a".to_string(), 1),
+            span: None,
+            point: Point(0.5, 1.5),
+        },
+        Row {
+            bbox: (1., 2., 3., 4.),
+            pair: ("b".to_string(), 2),
+            span: Some((10, 20)),
+            point: Point(2.5, 3.5),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(
+        df["bbox"].dtype(),
+        &DataType::Array(Box::new(DataType::Float32), 4)
+    );
+    assert_eq!(
+        df["pair"].dtype(),
+        &DataType::Struct(vec![
+            Field::new("field_0", DataType::String),
+            Field::new("field_1", DataType::UInt32),
+        ])
+    );
+    assert_eq!(
+        df["span"].dtype(),
+        &DataType::Array(Box::new(DataType::Int64), 2)
+    );
+    assert_eq!(df["span"].null_count(), 1);
+    assert_eq!(
+        df["point"].dtype(),
+        &DataType::Array(Box::new(DataType::Float64), 2)
+    );
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[test]
+fn ser_fixed_size_arrays() {
+    use crate::{PlRowSerdeError, SerializeOptions};
+    use polars::datatypes::DataType;
+
+    let array = |dtype, width| DataType::Array(Box::new(dtype), width);
+    fn read_all<T: serde::de::DeserializeOwned>(df: &DataFrame) -> Vec<T> {
+        crate::deserialize_all_borrowed(df)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Pixel {
+        rgba: [u8; 4],
+        pairs: Vec<[i16; 2]>,
+    }
+    let rows = vec![
+        Pixel {
+            rgba: [1, 2, 3, 4],
+            pairs: vec![[1, 2], [3, 4]],
+        },
+        Pixel {
+            rgba: [5, 6, 7, 8],
+            pairs: vec![],
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df["rgba"].dtype(), &array(DataType::UInt8, 4));
+    assert_eq!(
+        df["pairs"].dtype(),
+        &DataType::List(Box::new(array(DataType::Int16, 2)))
+    );
+    assert_eq!(read_all::<Pixel>(&df), rows);
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Embedding {
+        embedding: Vec<f32>,
+    }
+    let embedding = |values: &[f32]| Embedding {
+        embedding: values.to_vec(),
+    };
+    let rows = vec![embedding(&[0.1, 0.2, 0.3]), embedding(&[0.4, 0.5, 0.6])];
+    let options = SerializeOptions::new().with_seq_as_array(true);
+    let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+    assert_eq!(df["embedding"].dtype(), &array(DataType::Float32, 3));
+    assert_eq!(read_all::<Embedding>(&df), rows);
+
+    let rows = [embedding(&[0.1, 0.2]), embedding(&[0.3])];
+    let options = SerializeOptions::new().with_seq_as_array(true);
+    let err = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap_err();
+    assert_eq!(
+        err,
+        PlRowSerdeError::LengthMismatch {
+            column: Some("embedding".to_string()),
+            row: Some(1),
+            expected: 2,
+            found: 1,
+        }
+    );
+
+    // every seq empty: no Arrays of width 0, the lists stay
+    let rows = vec![embedding(&[]), embedding(&[])];
+    let options = SerializeOptions::new().with_seq_as_array(true);
+    let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+    assert!(matches!(df["embedding"].dtype(), DataType::List(_)));
+    assert_eq!(read_all::<Embedding>(&df), rows);
+
+    // elements that are only ever null take the dtype of the others
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Sparse {
+        values: [Option<f32>; 2],
+        pair: (Option<i32>, Option<i32>),
+        none: [i32; 0],
+    }
+    let rows = vec![
+        Sparse {
+            values: [Some(1.), None],
+            pair: (None, Some(1)),
+            none: [],
+        },
+        Sparse {
+            values: [Some(2.), None],
+            pair: (None, None),
+            none: [],
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df["values"].dtype(), &array(DataType::Float32, 2));
+    assert_eq!(df["pair"].dtype(), &array(DataType::Int32, 2));
+    assert!(matches!(df["none"].dtype(), DataType::List(_)));
+    assert_eq!(df["none"].len(), 2);
+    assert_eq!(read_all::<Sparse>(&df), rows);
+}
+
+#[test]
+fn ser_nested_lists_round_trip() {
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        grid: Vec<Vec<Option<i32>>>,
+        words: Vec<Option<Vec<String>>>,
+        cube: Option<Vec<Vec<Vec<u8>>>>,
+    }
+
+    let rows = vec![
+        Row {
+            grid: vec![vec![Some(1), None, Some(3)], vec![], vec![None]],
+            words: vec![Some(vec!["a".into(), "b".into()]), None],
+            cube: None,
+        },
+        Row {
+            grid: vec![],
+            words: vec![None, Some(vec![]), Some(vec!["c".into()])],
+            cube: Some(vec![vec![vec![1, 2], vec![]], vec![], vec![vec![3]]]),
+        },
+        Row {
+            grid: vec![vec![None, None]],
+            words: vec![],
+            cube: Some(vec![]),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    let list = |dtype| DataType::List(Box::new(dtype));
+    assert_eq!(df["grid"].dtype(), &list(list(DataType::Int32)));
+    assert_eq!(df["words"].dtype(), &list(list(DataType::String)));
+    assert_eq!(df["cube"].dtype(), &list(list(list(DataType::UInt8))));
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[test]
+fn ser_bytes_to_binary() {
+    use polars::datatypes::DataType;
+    use serde_bytes::ByteBuf;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "serde_bytes")]
+        blob: Vec<u8>,
+        #[serde(with = "serde_bytes")]
+        boxed: Box<[u8]>,
+        buf: Option<ByteBuf>,
+        list: Vec<u8>,
+    }
+
+    let rows = vec![
+        Row {
+            blob: b"abc".to_vec(),
+            boxed: Box::new([0, 255]),
+            buf: Some(ByteBuf::from(vec![1, 2])),
+            list: vec![4, 5, 6],
+        },
+        Row {
+            blob: vec![],
+            boxed: Box::new([]),
+            buf: None,
+            list: vec![7, 8, 9],
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df["blob"].dtype(), &DataType::Binary);
+    assert_eq!(df["boxed"].dtype(), &DataType::Binary);
+    assert_eq!(df["buf"].dtype(), &DataType::Binary);
+    assert_eq!(
+        df["list"].dtype(),
+        &DataType::List(Box::new(DataType::UInt8))
+    );
+    assert_eq!(df["buf"].null_count(), 1);
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // Binary, BinaryOffset and List(UInt8) cells read into any bytes target
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Bytes {
+        blob: ByteBuf,
+        list: ByteBuf,
+        offset: Vec<u8>,
+        fixed: [u8; 3],
+    }
+
+    let df = df
+        .lazy()
+        .select([
+            pl::col("blob"),
+            pl::col("list"),
+            pl::col("blob").cast(DataType::BinaryOffset).alias("offset"),
+            pl::col("list").alias("fixed"),
+        ])
+        .collect()
+        .unwrap()
+        .head(Some(1));
+    let back: Vec<Bytes> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        back,
+        vec![Bytes {
+            blob: ByteBuf::from(b"abc".to_vec()),
+            list: ByteBuf::from(vec![4, 5, 6]),
+            offset: b"abc".to_vec(),
+            fixed: [4, 5, 6],
+        }]
+    );
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Fixed {
+        blob: [u8; 3],
+    }
+
+    let back: Vec<Fixed> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, vec![Fixed { blob: *b"abc" }]);
+
+    // the cell must have exactly as many bytes as the array
+    #[derive(serde::Deserialize, Debug)]
+    struct Short {
+        #[allow(dead_code)]
+        blob: [u8; 2],
+    }
+    let err = crate::deserialize_single_row_borrowed::<Short>(&df, 0).unwrap_err();
+    assert!(
+        matches!(
+            err,
+            crate::PlRowSerdeError::LengthMismatch {
+                expected: 2,
+                found: 3,
+                ..
+            }
+        ),
+        "{err:?}"
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_round_trip() {
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use polars::datatypes::{DataType, TimeUnit};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "crate::temporal")]
+        date: NaiveDate,
+        #[serde(with = "crate::temporal")]
+        naive: NaiveDateTime,
+        #[serde(with = "crate::temporal")]
+        utc: DateTime<Utc>,
+        #[serde(with = "crate::temporal")]
+        offset: DateTime<FixedOffset>,
+        #[serde(with = "crate::temporal")]
+        time: NaiveTime,
+        #[serde(with = "crate::temporal")]
+        maybe: Option<NaiveDate>,
+    }
+
+    let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let naive = date.and_hms_micro_opt(13, 14, 15, 161_718).unwrap();
+    let rows = vec![
+        Row {
+            date,
+            naive,
+            utc: naive.and_utc(),
+            offset: DateTime::parse_from_rfc3339("2024-02-29T13:14:15.161718+02:00").unwrap(),
+            time: NaiveTime::from_hms_nano_opt(23, 59, 59, 123_456_789).unwrap(),
+            maybe: None,
+        },
+        Row {
+            date: NaiveDate::from_ymd_opt(1969, 7, 20).unwrap(),
+            naive: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
+            utc: DateTime::from_timestamp(-1, 0).unwrap(),
+            offset: DateTime::parse_from_rfc3339("1969-07-20T20:17:40-05:00").unwrap(),
+            time: NaiveTime::MIN,
+            maybe: Some(date),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    let utc = DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into()));
+    assert_eq!(df["date"].dtype(), &DataType::Date);
+    assert_eq!(
+        df["naive"].dtype(),
+        &DataType::Datetime(TimeUnit::Microseconds, None)
+    );
+    assert_eq!(df["utc"].dtype(), &utc);
+    assert_eq!(df["offset"].dtype(), &utc);
+    assert_eq!(df["time"].dtype(), &DataType::Time);
+    assert_eq!(df["maybe"].dtype(), &DataType::Date);
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+    // the same instant, but the offset isn't kept
+    assert_eq!(back[0].offset.offset(), &FixedOffset::east_opt(0).unwrap());
+
+    // finer units keep the nanoseconds, coarser ones truncate them
+    let mut nanos = rows.iter().map(|row| row.naive).collect::<Vec<_>>();
+    nanos[1] = DateTime::from_timestamp(-1, 999_999_999)
+        .unwrap()
+        .naive_utc();
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Naive {
+        #[serde(with = "crate::temporal")]
+        naive: NaiveDateTime,
+    }
+    let nanos = nanos
+        .into_iter()
+        .map(|naive| Naive { naive })
+        .collect::<Vec<_>>();
+    let options = crate::SerializeOptions::new().with_datetime_unit(TimeUnit::Nanoseconds);
+    let df = crate::serialize_into_dataframe_with_options(nanos.iter(), options).unwrap();
+    assert_eq!(
+        df["naive"].dtype(),
+        &DataType::Datetime(TimeUnit::Nanoseconds, None)
+    );
+    let back: Vec<Naive> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, nanos);
+
+    let options = crate::SerializeOptions::new().with_datetime_unit(TimeUnit::Milliseconds);
+    let df = crate::serialize_into_dataframe_with_options(nanos.iter(), options).unwrap();
+    let back: Vec<Naive> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        back[0].naive,
+        date.and_hms_milli_opt(13, 14, 15, 161).unwrap()
+    );
+    assert_eq!(
+        back[1].naive,
+        DateTime::from_timestamp(-1, 999_000_000)
+            .unwrap()
+            .naive_utc()
+    );
+
+    // columns in other time units are read without the attribute
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct At {
+        at: NaiveDateTime,
+    }
+
+    let df = DataFrame::new(vec![Series::new("at", [1_700_000_000_123_456_789i64])
+        .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+        .unwrap()])
+    .unwrap();
+    let at: At = crate::deserialize_single_row_borrowed(&df, 0).unwrap();
+    assert_eq!(
+        at.at,
+        DateTime::from_timestamp(1_700_000_000, 123_456_789)
+            .unwrap()
+            .naive_utc()
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_csv_dates() {
+    use chrono::NaiveDate;
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Deserialize, Debug)]
+    struct Item {
+        customer_id: String,
+        subscription_date: NaiveDate,
+    }
+
+    let df = polars::io::csv::read::CsvReader::new(Cursor::new(include_bytes!(
+        "./test-assets/test.csv"
+    )))
+    .finish()
+    .unwrap()
+    .lazy()
+    .with_column(pl::col("subscription_date").cast(DataType::Date))
+    .collect()
+    .unwrap();
+
+    let items: Vec<Item> = crate::deserialize_all(df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(items[0].customer_id, "DD37Cf93aecA6Dc");
+    assert_eq!(
+        items[0].subscription_date,
+        NaiveDate::from_ymd_opt(2020, 8, 24).unwrap()
+    );
+}
+
+#[test]
+fn ser_durations() {
+    use polars::datatypes::{DataType, TimeUnit};
+    use std::time::Duration;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        took: Duration,
+        timeout: Option<Duration>,
+    }
+
+    let rows = vec![
+        Row {
+            took: Duration::new(3, 141_592_653),
+            timeout: None,
+        },
+        Row {
+            took: Duration::ZERO,
+            timeout: Some(Duration::from_millis(1500)),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    let micros = DataType::Duration(TimeUnit::Microseconds);
+    assert_eq!(df["took"].dtype(), &micros);
+    assert_eq!(df["timeout"].dtype(), &micros);
+
+    // finer parts than the unit are truncated
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back[0].took, Duration::new(3, 141_592_000));
+    assert_eq!(back[1], rows[1]);
+
+    let options = crate::SerializeOptions::new().with_duration_unit(TimeUnit::Nanoseconds);
+    let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+    assert_eq!(
+        df["took"].dtype(),
+        &DataType::Duration(TimeUnit::Nanoseconds)
+    );
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // or read as ticks of the unit of the column
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Ticks {
+        took: i64,
+        timeout: Option<i64>,
+    }
+
+    let options = crate::SerializeOptions::new().with_duration_unit(TimeUnit::Milliseconds);
+    let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+    let back: Vec<Ticks> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        back,
+        vec![
+            Ticks {
+                took: 3141,
+                timeout: None
+            },
+            Ticks {
+                took: 0,
+                timeout: Some(1500)
+            },
+        ]
+    );
+
+    // std durations can't be negative
+    let df = DataFrame::new(vec![Series::new("took", [-1i64])
+        .cast(&DataType::Duration(TimeUnit::Milliseconds))
+        .unwrap()])
+    .unwrap();
+    #[derive(serde::Deserialize, Debug)]
+    struct Took {
+        #[allow(dead_code)]
+        took: Duration,
+    }
+    assert!(crate::deserialize_single_row_borrowed::<Took>(&df, 0).is_err());
+}
+
+#[test]
+fn ser_structs_named_duration() {
+    use polars::datatypes::{DataType, Field};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Duration {
+        a: u32,
+        b: String,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    #[serde(rename = "Duration")]
+    struct Secs {
+        secs: u64,
+        unit: String,
+    }
+
+    // the fields of a `std::time::Duration`, but not their types
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    #[serde(rename = "Duration")]
+    struct Text {
+        secs: String,
+        nanos: String,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        span: Duration,
+        secs: Secs,
+        text: Text,
+    }
+
+    let rows = vec![
+        Row {
+            span: Duration {
+                a: 1,
+                b: "one".into(),
+            },
+            secs: Secs {
+                secs: 2,
+                unit: "s".into(),
+            },
+            text: Text {
+                secs: "5".into(),
+                nanos: "6".into(),
+            },
+        },
+        Row {
+            span: Duration {
+                a: 3,
+                b: "three".into(),
+            },
+            secs: Secs {
+                secs: 4,
+                unit: "min".into(),
+            },
+            text: Text {
+                secs: "7".into(),
+                nanos: "8".into(),
+            },
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(
+        df["span"].dtype(),
+        &DataType::Struct(vec![
+            Field::new("a", DataType::UInt32),
+            Field::new("b", DataType::String),
+        ])
+    );
+    assert_eq!(
+        df["secs"].dtype(),
+        &DataType::Struct(vec![
+            Field::new("secs", DataType::UInt64),
+            Field::new("unit", DataType::String),
+        ])
+    );
+    assert_eq!(
+        df["text"].dtype(),
+        &DataType::Struct(vec![
+            Field::new("secs", DataType::String),
+            Field::new("nanos", DataType::String),
+        ])
+    );
+
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_durations() {
+    use chrono::Duration;
+    use polars::datatypes::{DataType, TimeUnit};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "crate::temporal")]
+        delta: Duration,
+        #[serde(with = "crate::temporal")]
+        maybe: Option<Duration>,
+    }
+
+    let rows = vec![
+        Row {
+            delta: Duration::milliseconds(-1500),
+            maybe: Some(Duration::days(2)),
+        },
+        Row {
+            delta: Duration::microseconds(7),
+            maybe: None,
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(
+        df["delta"].dtype(),
+        &DataType::Duration(TimeUnit::Microseconds)
+    );
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+}
+
+#[test]
+fn deser_decimal_unscaled() {
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        amount: i128,
+        fee: Option<i128>,
+    }
+
+    let df = df!(
+        "amount" => [12i64, -3],
+        "fee" => [Some(1i64), None],
+    )
+    .unwrap()
+    .lazy()
+    .with_columns([
+        pl::col("amount").cast(DataType::Decimal(Some(20), Some(2))),
+        pl::col("fee").cast(DataType::Decimal(Some(20), Some(3))),
+    ])
+    .collect()
+    .unwrap();
+    let rows: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                amount: 1200,
+                fee: Some(1000)
+            },
+            Row {
+                amount: -300,
+                fee: None
+            },
+        ]
+    );
+
+    // and lazily, through the row cursor
+    let lazy = crate::iter_rows::<Row>(&df)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(lazy, rows);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn rust_decimal_round_trip() {
+    use polars::datatypes::DataType;
+    use rust_decimal::Decimal;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        #[serde(with = "crate::decimal")]
+        amount: Decimal,
+        #[serde(with = "crate::decimal")]
+        fee: Option<Decimal>,
+    }
+
+    let dec = |s: &str| s.parse::<Decimal>().unwrap();
+    let rows = vec![
+        Row {
+            amount: dec("12.5"),
+            fee: None,
+        },
+        Row {
+            amount: dec("-0.001"),
+            fee: Some(dec("79228162514264337593543950335")),
+        },
+    ];
+    // the scale grows to the most decimals of the column
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df["amount"].dtype(), &DataType::Decimal(Some(38), Some(3)));
+    assert_eq!(df["fee"].dtype(), &DataType::Decimal(Some(38), Some(0)));
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // a fixed scale fails for values with more decimals
+    let options = crate::SerializeOptions::new().with_decimal_scale(2);
+    let err = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap_err();
+    assert_eq!(err.column(), Some("amount"));
+    assert_eq!(err.row(), Some(1));
+
+    // and for values past the precision
+    let rows = [Row {
+        amount: Decimal::MAX,
+        fee: None,
+    }];
+    let options = crate::SerializeOptions::new().with_decimal_scale(10);
+    let err = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap_err();
+    assert_eq!(err.column(), Some("amount"));
+}
+
+#[test]
+fn ser_int128_layouts() {
+    use polars::datatypes::DataType;
+
+    use crate::{Int128Layout, SerializeOptions};
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+    struct Row {
+        id: i128,
+        count: u128,
+        parent: Option<i128>,
+    }
+
+    let big = 10i128.pow(38) - 1;
+    let rows = vec![
+        Row {
+            id: -big,
+            count: big as u128,
+            parent: None,
+        },
+        Row {
+            id: 0,
+            count: 1,
+            parent: Some(big),
+        },
+    ];
+    let df = crate::serialize_into_dataframe(rows.iter()).unwrap();
+    assert_eq!(df["id"].dtype(), &DataType::Decimal(Some(38), Some(0)));
+    assert_eq!(df["count"].dtype(), &DataType::Decimal(Some(38), Some(0)));
+    let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    // values past 38 digits only fit the other layouts
+    let mut rows = rows;
+    rows[0].id = i128::MIN;
+    rows[1].count = u128::MAX;
+    let err = crate::serialize_into_dataframe(rows.iter()).unwrap_err();
+    assert_eq!(err.column(), Some("id"));
+    for (layout, dtype) in [
+        (Int128Layout::String, DataType::String),
+        (Int128Layout::Binary, DataType::Binary),
+    ] {
+        let options = SerializeOptions::new().with_int128_layout(layout);
+        let df = crate::serialize_into_dataframe_with_options(rows.iter(), options).unwrap();
+        assert_eq!(df["id"].dtype(), &dtype);
+        assert_eq!(df["count"].dtype(), &dtype);
+        let back: Vec<Row> = crate::deserialize_all_borrowed(&df)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(back, rows);
+    }
+
+    // narrower ints widen, negative ones don't fit a u128
+    let df = df!(
+        "id" => [-1i64],
+        "count" => [-1i64],
+        "parent" => [Some(2u32)],
+    )
+    .unwrap();
+    let err = crate::deserialize_single_row_borrowed::<Row>(&df, 0).unwrap_err();
+    assert_eq!(err.column(), Some("count"));
+    let df = df!(
+        "id" => [-1i64],
+        "count" => ["7"],
+        "parent" => [Some(2u32)],
+    )
+    .unwrap();
+    let row: Row = crate::deserialize_single_row_borrowed(&df, 0).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            id: -1,
+            count: 7,
+            parent: Some(2)
+        }
+    );
+}
+
+#[test]
+fn deser_strings_from_any_text_column() {
+    use polars::datatypes::DataType;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Row<'a> {
+        owned: String,
+        // borrowed unless the column is Categorical
+        #[serde(borrow)]
+        borrowed: Cow<'a, str>,
+        letter: char,
+        bytes: Vec<u8>,
+        #[serde(with = "serde_bytes")]
+        buf: Vec<u8>,
+    }
+
+    let frame = |dtype: DataType| {
+        df!(
+            "owned" => ["été", "x"],
+            "borrowed" => ["日本", "y"],
+            "letter" => ["é", "😀"],
+            "bytes" => ["ab", ""],
+            "buf" => ["ç", "z"],
+        )
+        .unwrap()
+        .lazy()
+        .select([pl::all().cast(dtype)])
+        .collect()
+        .unwrap()
+    };
+    let expected = vec![
+        Row {
+            owned: "été".into(),
+            borrowed: "日本".into(),
+            letter: 'é',
+            bytes: b"ab".to_vec(),
+            buf: "ç".as_bytes().to_vec(),
+        },
+        Row {
+            owned: "x".into(),
+            borrowed: "y".into(),
+            letter: '😀',
+            bytes: vec![],
+            buf: b"z".to_vec(),
+        },
+    ];
+    for dtype in [
+        DataType::String,
+        DataType::Categorical(None, Default::default()),
+        DataType::Binary,
+    ] {
+        let df = frame(dtype.clone());
+        let rows = crate::deserialize_all_borrowed::<Row>(&df);
+        let rows = rows.into_iter().collect::<Result<Vec<_>, _>>();
+        assert_eq!(rows.as_ref(), Ok(&expected), "{dtype:?}");
+    }
+
+    // more than one character, or bytes that aren't UTF-8
+    #[derive(serde::Deserialize, Debug)]
+    struct Letter {
+        #[allow(dead_code)]
+        letter: char,
+    }
+    let df = df!("letter" => ["ab"]).unwrap();
+    let err = crate::deserialize_single_row_borrowed::<Letter>(&df, 0).unwrap_err();
+    assert_eq!(err.column(), Some("letter"));
+    let df = df!("letter" => [&[0xffu8][..]]).unwrap();
+    assert!(crate::deserialize_single_row_borrowed::<Letter>(&df, 0).is_err());
+}
+
+#[test]
+fn tuple_rows_round_trip() {
+    use crate::SerializeOptions;
+
+    let rows = vec![
+        (1i64, "a".to_string(), 0.5f64),
+        (2, "b".to_string(), 1.5),
+        (3, "c".to_string(), 2.5),
+    ];
+    let df = crate::serialize_into_dataframe(rows.clone().into_iter()).unwrap();
+    assert_eq!(df.get_column_names(), ["column_0", "column_1", "column_2"]);
+    let back: Vec<(i64, String, f64)> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, rows);
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Point(f64, f64);
+
+    let points = vec![Point(0.0, 1.0), Point(2.0, 3.0)];
+    let options = SerializeOptions::new().with_column_names(["x", "y"]);
+    let df = crate::serialize_into_dataframe_with_options(points.iter(), options).unwrap();
+    assert_eq!(df.get_column_names(), ["x", "y"]);
+    let back: Vec<Point> = crate::deserialize_all_borrowed(&df)
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(back, points);
+
+    // columns are read by position, extra columns are ignored
+    let df = df!("b" => [1i32, 2], "a" => ["x", "y"], "c" => [true, false]).unwrap();
+    let back: (i32, String) = crate::deserialize_single_row_borrowed(&df, 1).unwrap();
+    assert_eq!(back, (2, "y".to_string()));
+    assert!(crate::deserialize_single_row_borrowed::<(i32, String, bool, u8)>(&df, 0).is_err());
+}
+
+// Random dataframes and random rows go through the public API. Errors are
+// fine, panics are not.
+mod no_panic {
+    use std::collections::{HashMap, HashSet};
+
+    use polars::{
+        datatypes::{create_enum_data_type, DataType, StructChunked, TimeUnit},
+        export::arrow::array::Utf8ViewArray,
+        frame::DataFrame,
+        prelude::{IntoSeries, ListChunked, NamedFrom},
+        series::Series,
+    };
+    use proptest::{collection::vec, option, prelude::*};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{Int128Layout, SerializeOptions};
+
+    const NAMES: [&str; 4] = ["a", "b", "c", "d"];
+    const MAX_LEN: usize = 6;
+    const KINDS: usize = 29;
+
+    /// Column `name` of one of `KINDS` dtypes built from the generated values.
+    fn column(name: &str, kind: usize, ints: &[Option<i64>], strs: &[Option<String>]) -> Series {
+        let int64 = Series::new(name, ints);
+        let cast = |dtype: DataType| int64.cast(&dtype).unwrap();
+        let list = |ints: &[Option<i64>], fixed: bool| {
+            let ca: ListChunked = ints
+                .iter()
+                .map(|v| {
+                    v.map(|v| {
+                        let len = if fixed { 2 } else { v.rem_euclid(3) as usize };
+                        Series::new("", vec![v; len])
+                    })
+                })
+                .collect();
+            ca.into_series().with_name(name)
+        };
+        let strukt = |name: &str, ints: &[Option<i64>], strs: &[Option<String>]| {
+            StructChunked::new(name, &[Series::new("a", ints), Series::new("b", strs)])
+                .unwrap()
+                .into_series()
+        };
+        match kind {
+            0 => Series::new(
+                name,
+                ints.iter()
+                    .map(|v| v.map(|v| v % 2 == 0))
+                    .collect::<Vec<_>>(),
+            ),
+            1 => cast(DataType::Int8),
+            2 => cast(DataType::Int16),
+            3 => cast(DataType::Int32),
+            4 => int64,
+            5 => cast(DataType::UInt8),
+            6 => cast(DataType::UInt16),
+            7 => cast(DataType::UInt32),
+            8 => cast(DataType::UInt64),
+            9 => cast(DataType::Float32),
+            10 => cast(DataType::Float64),
+            11 => Series::new(name, strs),
+            12 => Series::new(
+                name,
+                strs.iter()
+                    .map(|s| s.as_ref().map(|s| s.as_bytes().to_vec()))
+                    .collect::<Vec<_>>(),
+            ),
+            13 => Series::new_null(name, ints.len()),
+            14 => Series::new(name, strs)
+                .cast(&DataType::Categorical(None, Default::default()))
+                .unwrap(),
+            15 => cast(DataType::Int32).cast(&DataType::Date).unwrap(),
+            16 => cast(DataType::Datetime(TimeUnit::Milliseconds, None)),
+            17 => Series::new(
+                name,
+                ints.iter()
+                    .map(|v| v.map(|v| v.rem_euclid(86_400_000_000_000)))
+                    .collect::<Vec<_>>(),
+            )
+            .cast(&DataType::Time)
+            .unwrap(),
+            18 => cast(DataType::Duration(TimeUnit::Microseconds)),
+            19 => cast(DataType::Decimal(Some(20), Some(2))),
+            20 => list(ints, false),
+            // casting to an array needs every list to be there
+            21 => list(
+                &ints
+                    .iter()
+                    .map(|v| Some(v.unwrap_or(0)))
+                    .collect::<Vec<_>>(),
+                true,
+            )
+            .cast(&DataType::Array(Box::new(DataType::Int64), 2))
+            .unwrap(),
+            22 => strukt(name, ints, strs),
+            23 => Series::new(
+                name,
+                ints.iter()
+                    .map(|v| v.map(|v| ["X", "Y", "Z"][v.rem_euclid(3) as usize]))
+                    .collect::<Vec<_>>(),
+            )
+            .cast(&create_enum_data_type(Utf8ViewArray::from_slice_values([
+                "X", "Y", "Z",
+            ])))
+            .unwrap(),
+            24 => column(name, 12, ints, strs)
+                .cast(&DataType::BinaryOffset)
+                .unwrap(),
+            25 => cast(DataType::Datetime(
+                TimeUnit::Nanoseconds,
+                Some("UTC".into()),
+            )),
+            26 => cast(DataType::Duration(TimeUnit::Nanoseconds)),
+            27 => cast(DataType::Decimal(Some(38), Some(10))),
+            _ => {
+                let ca: ListChunked = ints
+                    .iter()
+                    .map(|v| {
+                        v.map(|v| {
+                            let len = v.rem_euclid(3) as usize;
+                            strukt("", &vec![Some(v); len], &vec![None; len])
+                        })
+                    })
+                    .collect();
+                ca.into_series().with_name(name)
+            }
+        }
+    }
+
+    prop_compose! {
+        fn arb_dataframe()(
+            len in 0..=MAX_LEN,
+            columns in vec((0..NAMES.len(), 0..KINDS), 0..6),
+            ints in vec(option::of(any::<i64>()), MAX_LEN),
+            strs in vec(option::of(".{0,3}"), MAX_LEN),
+            split in 0..=MAX_LEN,
+        ) -> DataFrame {
+            let mut seen = HashSet::new();
+            let columns = columns
+                .into_iter()
+                .filter(|(name, _)| seen.insert(*name))
+                .enumerate()
+                .map(|(idx, (name, kind))| {
+                    let mut ints = ints.clone();
+                    ints.rotate_left(idx);
+                    column(NAMES[name], kind, &ints[..len], &strs[..len])
+                })
+                .collect::<Vec<_>>();
+            let df = DataFrame::new(columns).unwrap();
+            if split > 0 && split < len {
+                let mut head = df.slice(0, split);
+                head.vstack_mut(&df.slice(split as i64, len - split)).unwrap();
+                head
+            } else {
+                df
+            }
+        }
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[serde(untagged)]
+    enum Anything {
+        Bool(bool),
+        Int(i64),
+        UInt(u64),
+        Float(f64),
+        Str(String),
+        Bytes(Vec<u8>),
+        Seq(Vec<Anything>),
+        Map(HashMap<String, Anything>),
+        Unit(()),
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Nested {
+        a: Option<i64>,
+        b: String,
+    }
+
+    #[derive(Deserialize, Debug)]
+    enum Kind {
+        X,
+        Y,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Strict {
+        a: i64,
+        b: Option<String>,
+        c: Vec<i32>,
+        d: Option<Nested>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Loose {
+        a: Option<f64>,
+        b: Option<Vec<u8>>,
+        c: Option<(i64, i64)>,
+        d: Option<char>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Borrowing<'a> {
+        #[serde(borrow)]
+        a: Option<&'a str>,
+        #[serde(borrow)]
+        b: Option<&'a [u8]>,
+        c: Option<Kind>,
+        d: Option<Vec<Option<Nested>>>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Typed {
+        a: Option<i128>,
+        b: Option<std::time::Duration>,
+        c: Option<[u8; 2]>,
+        d: Option<u128>,
+    }
+
+    #[cfg(feature = "chrono")]
+    #[derive(Deserialize, Debug)]
+    struct Chrono {
+        a: Option<chrono::NaiveDateTime>,
+        b: Option<chrono::DateTime<chrono::Utc>>,
+        #[serde(with = "crate::temporal")]
+        c: Option<chrono::Duration>,
+        d: Option<chrono::NaiveDate>,
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[derive(Deserialize, Debug)]
+    struct Decimals {
+        a: Option<rust_decimal::Decimal>,
+        b: Option<rust_decimal::Decimal>,
+        c: Option<rust_decimal::Decimal>,
+        d: Option<rust_decimal::Decimal>,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Dynamic {
+        a: Option<Anything>,
+        b: Option<Anything>,
+        c: Option<Anything>,
+        d: Option<Anything>,
+    }
+
+    fn read_every_way<T>(df: &DataFrame)
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let _ = crate::deserialize_all::<T>(df.clone());
+        let _ = crate::iter_rows::<T>(df).count();
+        let mut cursor = crate::RowCursor::<T>::new(df);
+        for row_idx in (0..df.height() + 2).rev() {
+            let _ = cursor.row(row_idx);
+            let _ = crate::deserialize_single_row::<T>(df.clone(), row_idx);
+        }
+    }
+
+    #[derive(Serialize, Debug, Clone)]
+    enum Shape {
+        Unit,
+        Newtype(i32),
+        Tuple(i32, String),
+        Struct { x: f64 },
+    }
+
+    #[derive(Serialize, Debug, Clone)]
+    #[serde(untagged)]
+    enum Untagged {
+        Int(i64),
+        Str(String),
+        Float(f64),
+        Bool(bool),
+    }
+
+    #[derive(Serialize, Debug, Clone)]
+    struct Inner {
+        x: i64,
+        y: Option<String>,
+    }
+
+    #[derive(Serialize, Debug, Clone)]
+    struct Row {
+        int: i64,
+        opt: Option<i32>,
+        text: String,
+        chr: char,
+        bytes: Vec<u8>,
+        list: Vec<Option<i16>>,
+        nested: Vec<Vec<u8>>,
+        pair: (i32, String),
+        map: HashMap<String, u8>,
+        inner: Option<Inner>,
+        shape: Shape,
+        loose: Untagged,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skipped: Option<u64>,
+        took: std::time::Duration,
+        wide: i128,
+        fixed: [u8; 2],
+    }
+
+    #[cfg(feature = "chrono")]
+    #[derive(Serialize, Debug, Clone)]
+    struct Dated {
+        #[serde(with = "crate::temporal")]
+        at: chrono::NaiveDateTime,
+        #[serde(with = "crate::temporal")]
+        zoned: Option<chrono::DateTime<chrono::FixedOffset>>,
+        #[serde(with = "crate::temporal")]
+        delta: chrono::Duration,
+        #[cfg(feature = "rust_decimal")]
+        #[serde(with = "crate::decimal")]
+        amount: rust_decimal::Decimal,
+    }
+
+    /// Options that take the serializer off its default paths.
+    fn options() -> [SerializeOptions; 3] {
+        [
+            SerializeOptions::new(),
+            SerializeOptions::new()
+                .with_seq_as_array(true)
+                .with_duration_unit(TimeUnit::Nanoseconds)
+                .with_datetime_unit(TimeUnit::Nanoseconds)
+                .with_int128_layout(Int128Layout::String),
+            SerializeOptions::new()
+                .with_duration_unit(TimeUnit::Milliseconds)
+                .with_datetime_unit(TimeUnit::Milliseconds)
+                .with_decimal_scale(2)
+                .with_int128_layout(Int128Layout::Binary),
+        ]
+    }
+
+    fn arb_shape() -> impl Strategy<Value = Shape> {
+        prop_oneof![
+            Just(Shape::Unit),
+            any::<i32>().prop_map(Shape::Newtype),
+            (any::<i32>(), ".{0,3}").prop_map(|(a, b)| Shape::Tuple(a, b)),
+            any::<f64>().prop_map(|x| Shape::Struct { x }),
+        ]
+    }
+
+    fn arb_untagged() -> impl Strategy<Value = Untagged> {
+        prop_oneof![
+            any::<i64>().prop_map(Untagged::Int),
+            ".{0,3}".prop_map(Untagged::Str),
+            any::<f64>().prop_map(Untagged::Float),
+            any::<bool>().prop_map(Untagged::Bool),
+        ]
+    }
+
+    prop_compose! {
+        fn arb_row()(
+            (int, opt, text, chr, bytes) in (
+                any::<i64>(),
+                option::of(any::<i32>()),
+                ".{0,3}",
+                any::<char>(),
+                vec(any::<u8>(), 0..3),
+            ),
+            (list, nested, pair, map) in (
+                vec(option::of(any::<i16>()), 0..3),
+                vec(vec(any::<u8>(), 0..3), 0..3),
+                (any::<i32>(), ".{0,3}"),
+                proptest::collection::hash_map(".{0,2}", any::<u8>(), 0..3),
+            ),
+            (inner, shape, loose, skipped) in (
+                option::of((any::<i64>(), option::of(".{0,3}")).prop_map(|(x, y)| Inner { x, y })),
+                arb_shape(),
+                arb_untagged(),
+                option::of(any::<u64>()),
+            ),
+            (took, wide, fixed) in (
+                (any::<u64>(), 0..1_000_000_000u32)
+                    .prop_map(|(secs, nanos)| std::time::Duration::new(secs, nanos)),
+                any::<i128>(),
+                any::<[u8; 2]>(),
+            ),
+        ) -> Row {
+            Row {
+                int, opt, text, chr, bytes, list, nested, pair, map, inner, shape, loose,
+                skipped, took, wide, fixed,
+            }
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    prop_compose! {
+        fn arb_dated()(
+            secs in -100_000_000_000i64..100_000_000_000,
+            nanos in 0..1_000_000_000u32,
+            offset in option::of(-86_399..86_400i32),
+            delta in any::<i64>(),
+            (mantissa, scale) in (any::<i64>(), 0..=28u32),
+        ) -> Dated {
+            #[cfg(not(feature = "rust_decimal"))]
+            let _ = (mantissa, scale);
+            let at = chrono::DateTime::from_timestamp(secs, nanos).unwrap();
+            Dated {
+                at: at.naive_utc(),
+                zoned: offset.map(|offset| {
+                    at.with_timezone(&chrono::FixedOffset::east_opt(offset).unwrap())
+                }),
+                delta: chrono::Duration::nanoseconds(delta),
+                #[cfg(feature = "rust_decimal")]
+                amount: rust_decimal::Decimal::new(mantissa, scale),
+            }
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn deserialize_random_dataframes(df in arb_dataframe()) {
+            read_every_way::<Strict>(&df);
+            read_every_way::<Loose>(&df);
+            read_every_way::<Dynamic>(&df);
+            read_every_way::<Typed>(&df);
+            #[cfg(feature = "chrono")]
+            read_every_way::<Chrono>(&df);
+            #[cfg(feature = "rust_decimal")]
+            read_every_way::<Decimals>(&df);
+            read_every_way::<HashMap<String, Anything>>(&df);
+            read_every_way::<Option<Strict>>(&df);
+            read_every_way::<(i64, String)>(&df);
+            read_every_way::<i64>(&df);
+            read_every_way::<Kind>(&df);
+            let _ = crate::deserialize_all_borrowed::<Borrowing>(&df);
+            let _ = crate::iter_rows::<Borrowing>(&df).count();
+        }
+
+        #[test]
+        fn serialize_random_rows(rows in vec(arb_row(), 0..4)) {
+            for options in options() {
+                let _ = crate::serialize_into_dataframe_with_options(rows.iter(), options);
+            }
+            for row in rows {
+                let _ = crate::serialize_item_into_dataframe(Some(row.clone()));
+                let _ = crate::serialize_item_into_dataframe(row.shape);
+                let _ = crate::serialize_item_into_dataframe(row.pair);
+                let _ = crate::serialize_item_into_dataframe(row.map);
+                let _ = crate::serialize_item_into_dataframe(row.list);
+                let _ = crate::serialize_item_into_dataframe(row.int);
+            }
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn serialize_random_temporal_rows(rows in vec(arb_dated(), 0..4)) {
+            for options in options() {
+                if let Ok(df) = crate::serialize_into_dataframe_with_options(rows.iter(), options) {
+                    read_every_way::<HashMap<String, Anything>>(&df);
+                }
+            }
+        }
+
+        #[test]
+        fn round_trip_random_rows(rows in vec(arb_row(), 0..4)) {
+            for options in options() {
+                if let Ok(df) = crate::serialize_into_dataframe_with_options(rows.iter(), options) {
+                    read_every_way::<Dynamic>(&df);
+                    read_every_way::<HashMap<String, Anything>>(&df);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Values the `temporal` and `decimal` modules wrap in the newtype structs of
+//! `ser_root`, which name the column to write them to.
+//!
+//! The serializer of this crate isn't human readable to the wrapped values,
+//! so it is handed the integers they are stored as instead of the strings
+//! chrono and rust_decimal serialize them as.
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Values written in the newtype struct naming their column, re-exported as
+/// `temporal::Temporal` and `decimal::DecimalValue`.
+pub trait Wrapped: Sized {
+    fn serialize_wrapped<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    fn deserialize_wrapped<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// A value and the integers it is written as to serializers that aren't
+/// human readable.
+pub(crate) trait Physical: Serialize + for<'de> Deserialize<'de> {
+    /// The newtype struct of `ser_root` naming the column.
+    const NAME: &'static str;
+
+    type Repr: Serialize + for<'de> Deserialize<'de>;
+
+    fn to_physical(&self) -> Self::Repr;
+
+    fn from_physical(repr: Self::Repr) -> Option<Self>;
+}
+
+impl<T: Physical> Wrapped for T {
+    fn serialize_wrapped<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(T::NAME, &AsPhysical(self))
+    }
+
+    fn deserialize_wrapped<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            return T::deserialize(deserializer);
+        }
+        let repr = T::Repr::deserialize(deserializer)?;
+        T::from_physical(repr)
+            .ok_or_else(|| D::Error::custom("integers out of the range of the value"))
+    }
+}
+
+impl<T: Wrapped> Wrapped for Option<T> {
+    fn serialize_wrapped<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Some(value) => serializer.serialize_some(&AsWrapped(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_wrapped<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<FromWrapped<T>>::deserialize(deserializer).map(|i| i.map(|i| i.0))
+    }
+}
+
+/// The value itself to human readable serializers, its [`Physical`] integers
+/// to the others.
+struct AsPhysical<'a, T>(&'a T);
+
+impl<T: Physical> Serialize for AsPhysical<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            self.0.serialize(serializer)
+        } else {
+            self.0.to_physical().serialize(serializer)
+        }
+    }
+}
+
+struct AsWrapped<'a, T>(&'a T);
+
+impl<T: Wrapped> Serialize for AsWrapped<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_wrapped(serializer)
+    }
+}
+
+struct FromWrapped<T>(T);
+
+impl<'de, T: Wrapped> Deserialize<'de> for FromWrapped<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_wrapped(deserializer).map(Self)
+    }
+}
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Wrapped,
+    S: Serializer,
+{
+    value.serialize_wrapped(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Wrapped,
+    D: Deserializer<'de>,
+{
+    T::deserialize_wrapped(deserializer)
+}